@@ -0,0 +1,43 @@
+//! Helpers for the per-day input generators, which build random but valid puzzle inputs for load
+//! and differential testing.
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// The random number generator every day's generator draws from, so that a seed always gives the
+/// same input.
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// A grid of characters, one row per line, with each cell set to `f()`.
+pub fn char_grid(rows: usize, cols: usize, mut f: impl FnMut() -> char) -> String {
+    let mut result = String::with_capacity(rows * (cols + 1));
+
+    for _ in 0..rows {
+        result.extend((0..cols).map(|_| f()));
+        result.push('\n');
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::Rng;
+
+    #[test]
+    fn test_char_grid() {
+        let digits = |seed| {
+            let mut rng = rng(seed);
+            char_grid(3, 4, || rng.gen_range('0'..='9'))
+        };
+
+        let grid = digits(7);
+        assert_eq!(3, grid.lines().count());
+        assert!(grid.lines().all(|l| l.len() == 4 && l.chars().all(|c| c.is_ascii_digit())));
+        assert_eq!(grid, digits(7));
+    }
+}
@@ -0,0 +1,174 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point, or equally the displacement between two points, on a 2D lattice.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point, or equally the displacement between two points, in a 3D lattice.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// The smallest box containing a set of 2D points, with both corners inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bounds2<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+/// The smallest box containing a set of 3D points, with both corners inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bounds3<T> {
+    pub min: Point3<T>,
+    pub max: Point3<T>,
+}
+
+/// |a - b|, without going below zero for unsigned types.
+fn abs_diff<T: Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+/// Implements the operations shared by `Point2` and `Point3`, along with their bounding boxes,
+/// in terms of their fields.
+macro_rules! impl_point {
+    ($point:ident, $bounds:ident, $($field:ident),+) => {
+        impl<T> $point<T> {
+            pub const fn new($($field: T),+) -> Self {
+                Self { $($field),+ }
+            }
+        }
+
+        impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> $point<T> {
+            /// Sum of the absolute differences along each axis.
+            pub fn manhattan_distance(&self, other: &Self) -> T {
+                let distances = [$(abs_diff(self.$field, other.$field)),+];
+                let mut distances = IntoIterator::into_iter(distances);
+                let first = distances.next().unwrap();
+
+                distances.fold(first, |acc, d| acc + d)
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        /// Scales each coordinate.
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                Self { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$field += other.$field;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$field -= other.$field;)+
+            }
+        }
+
+        impl<T: Copy + Ord> $bounds<T> {
+            /// The bounds of the given points, or `None` if there are none.
+            pub fn of(points: impl IntoIterator<Item = $point<T>>) -> Option<Self> {
+                let mut points = points.into_iter();
+                let first = points.next()?;
+
+                let bounds = points.fold(Self { min: first, max: first }, |bounds, p| Self {
+                    min: $point { $($field: bounds.min.$field.min(p.$field)),+ },
+                    max: $point { $($field: bounds.max.$field.max(p.$field)),+ },
+                });
+
+                Some(bounds)
+            }
+
+            pub fn contains(&self, point: &$point<T>) -> bool {
+                $((self.min.$field..=self.max.$field).contains(&point.$field))&&+
+            }
+        }
+    };
+}
+
+impl_point!(Point2, Bounds2, x, y);
+impl_point!(Point3, Bounds3, x, y, z);
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point3::new(1, -2, 3);
+        let b = Point3::new(-4, 5, 6);
+
+        assert_eq!(Point3::new(-3, 3, 9), a + b);
+        assert_eq!(Point3::new(5, -7, -3), a - b);
+        assert_eq!(Point3::new(2, -4, 6), a * 2);
+        assert_eq!(Point3::new(-1, 2, -3), -a);
+        assert_eq!(15, a.manhattan_distance(&b));
+
+        // unsigned points must not underflow.
+        assert_eq!(7, Point2::new(3u32, 9).manhattan_distance(&Point2::new(5, 4)));
+    }
+
+    #[test]
+    fn test_bounds() {
+        let points = vec![Point2::new(6, 10), Point2::new(0, 14), Point2::new(9, 10)];
+        let bounds = Bounds2::of(points).unwrap();
+
+        assert_eq!(Point2::new(0, 10), bounds.min);
+        assert_eq!(Point2::new(9, 14), bounds.max);
+        assert!(bounds.contains(&Point2::new(9, 14)));
+        assert!(!bounds.contains(&Point2::new(10, 14)));
+        assert_eq!(None, Bounds2::<i32>::of(vec![]));
+    }
+}
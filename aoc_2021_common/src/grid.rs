@@ -0,0 +1,296 @@
+use crate::parse::{numbered, Line};
+use crate::ParseError;
+
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Offsets to the neighbours sharing an edge with a cell.
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets to every neighbour of a cell, including diagonals.
+const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1), (-1, 0), (-1, 1),
+    (0, -1), (0, 1),
+    (1, -1), (1, 0), (1, 1),
+];
+
+/// A rectangular grid of cells, addressed by (row, column) from the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "GridData<T>"))]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+/// A grid as serialized, checked to fill its rows and columns before being loaded.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct GridData<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<GridData<T>> for Grid<T> {
+    type Error = String;
+
+    fn try_from(data: GridData<T>) -> Result<Self, String> {
+        Self::from_cells(data.rows, data.cols, data.cells)
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `value`.
+    pub fn new(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { rows, cols, cells: vec![value; rows * cols] }
+    }
+
+    /// A grid with each cell set to `f(row, col)`.
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..rows * cols).map(|i| f(i / cols, i % cols)).collect();
+
+        Self { rows, cols, cells }
+    }
+
+    /// A grid of the given cells, row by row. Fails unless they exactly fill at least one row and
+    /// column.
+    pub fn from_cells(rows: usize, cols: usize, cells: Vec<T>) -> Result<Self, String> {
+        if cells.is_empty() || rows.checked_mul(cols) != Some(cells.len()) {
+            return Err(format!(
+                "{} cells cannot fill a grid of {} rows and {} columns",
+                cells.len(),
+                rows,
+                cols
+            ));
+        }
+
+        Ok(Self { rows, cols, cells })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.index_of(row, col).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.index_of(row, col).map(move |i| &mut self.cells[i])
+    }
+
+    /// The cell at a signed position, or `None` if it lies outside the grid.
+    pub fn get_signed(&self, row: isize, col: isize) -> Option<&T> {
+        if row < 0 || col < 0 {
+            return None;
+        }
+
+        self.get(row as usize, col as usize)
+    }
+
+    fn index_of(&self, row: usize, col: usize) -> Option<usize> {
+        if row < self.rows && col < self.cols {
+            Some(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let cols = self.cols;
+
+        (0..self.len()).map(move |i| (i / cols, i % cols))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of 0, which a grid without columns would have.
+        self.cells.chunks(self.cols.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.cols.max(1)).take(self.rows)
+    }
+
+    /// Positions of the up to 4 cells sharing an edge with the given cell.
+    pub fn neighbours_4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(row, col, &ORTHOGONAL)
+    }
+
+    /// Positions of the up to 8 cells surrounding the given cell, including diagonally.
+    pub fn neighbours_8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(row, col, &ALL_AROUND)
+    }
+
+    fn offsets(
+        &self,
+        row: usize,
+        col: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (rows, cols) = (self.rows, self.cols);
+
+        offsets.iter().filter_map(move |(dr, dc)| {
+            let r = row.checked_add_signed(*dr).filter(|r| *r < rows)?;
+            let c = col.checked_add_signed(*dc).filter(|c| *c < cols)?;
+            Some((r, c))
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { rows: self.rows, cols: self.cols, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Parses one row per line, converting each character with `f`. Characters that `f` rejects
+    /// are reported as not being `expected`, e.g. "a digit".
+    pub fn parse_chars<'a>(
+        lines: impl IntoIterator<Item = Line<'a>>,
+        expected: &str,
+        f: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let (mut rows, mut cols) = (0, 0);
+        let mut next_line = 1;
+
+        for line in lines {
+            next_line = line.number + 1;
+            rows += 1;
+
+            let row_start = cells.len();
+
+            for (i, c) in line.text.char_indices() {
+                let cell = f(c)
+                    .ok_or_else(|| line.error(&line.text[i..i + c.len_utf8()], expected))?;
+                cells.push(cell);
+            }
+
+            let width = cells.len() - row_start;
+            if width == 0 {
+                return Err(line.error_at(1, "a row of cells"));
+            }
+            if rows == 1 {
+                cols = width;
+            } else if width != cols {
+                return Err(line.error_at(1, format!("a row of {} cells", cols)).found(line.text));
+            }
+        }
+
+        if cells.is_empty() {
+            return Err(ParseError::expected(next_line, 1, "a row of cells"));
+        }
+
+        Ok(Self { rows, cols, cells })
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid of single digits, one row per line.
+    pub fn parse_digits(lines: &[String]) -> Result<Self, ParseError> {
+        Self::parse_chars(numbered(lines), "a digit", |c| c.to_digit(10).map(|d| d as u8))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        match self.index_of(row, col) {
+            Some(i) => &self.cells[i],
+            None => panic!("({}, {}) is outside a {}x{} grid", row, col, self.rows, self.cols),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        match self.index_of(row, col) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("({}, {}) is outside a {}x{} grid", row, col, self.rows, self.cols),
+        }
+    }
+}
+
+/// Prints each row on its own line, with the cells side by side.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_digits() {
+        let grid = Grid::parse_digits(&lines("123\n456")).unwrap();
+        assert_eq!((2, 3), (grid.rows(), grid.cols()));
+        assert_eq!(6, grid[(1, 2)]);
+        assert_eq!(None, grid.get(2, 0));
+        assert_eq!(vec![&2, &5], grid.column(1).collect::<Vec<&u8>>());
+        assert_eq!("123\n456", grid.to_string());
+
+        let err = Grid::parse_digits(&lines("123\n4x6")).unwrap_err();
+        assert_eq!("2:2: expected a digit, found \"x\"", err.to_string());
+
+        let err = Grid::parse_digits(&lines("123\n45")).unwrap_err();
+        assert_eq!("2:1: expected a row of 3 cells, found \"45\"", err.to_string());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(vec![(0, 1), (1, 0)], grid.neighbours_4(0, 0).collect::<Vec<_>>());
+        assert_eq!(4, grid.neighbours_4(1, 1).count());
+        assert_eq!(3, grid.neighbours_8(2, 2).count());
+        assert_eq!(8, grid.neighbours_8(1, 1).count());
+    }
+}
@@ -0,0 +1,170 @@
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod params;
+pub mod parse;
+pub mod solve;
+
+pub use geometry::{Bounds2, Bounds3, Point2, Point3};
+pub use grid::Grid;
+pub use params::{Param, Params};
+pub use parse::ParseError;
+pub use solve::{solve_within, RunError, SolveError, SOLVE_BUDGET};
+
+use std::env;
+use std::fmt::Display;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// A single day's puzzle: how to parse its input and how to answer each part.
+pub trait Solution {
+    /// The day of the event this solution belongs to.
+    const DAY: u8;
+
+    /// Constants the parts depend on, which can be overridden through `part_1_with` and
+    /// `part_2_with`. Days declaring any should solve with the defaults in `part_1` and `part_2`.
+    const PARAMS: &'static [Param] = &[];
+
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError>;
+
+    /// Parses the file at `path`, or stdin if the path is "-".
+    fn parse_path(path: &Path) -> Result<Self::Input, ParseError> {
+        parse::parse_path(path, |input| Self::parse(input))
+    }
+
+    fn parse_stdin() -> Result<Self::Input, ParseError> {
+        parse::parse_stdin(|input| Self::parse(input))
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError>;
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError>;
+
+    fn part_1_with(input: &Self::Input, _params: &Params) -> Result<Self::Answer1, SolveError> {
+        Self::part_1(input)
+    }
+
+    fn part_2_with(input: &Self::Input, _params: &Params) -> Result<Self::Answer2, SolveError> {
+        Self::part_2(input)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part '{}', expected 1 or 2", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub value: String,
+    /// Time taken to solve the part, not including parsing.
+    pub elapsed: Duration,
+}
+
+/// Type-erased entry point to a day's `Solution`, so that days can be collected into a registry.
+pub type Runner = fn(&mut dyn BufRead, Option<Part>, &Params) -> Result<Vec<Answer>, RunError>;
+
+/// Parses `input` and solves the given part, or both parts if `None`, with the given values for
+/// the day's params.
+pub fn run<S: Solution>(
+    input: &mut dyn BufRead,
+    part: Option<Part>,
+    params: &Params,
+) -> Result<Vec<Answer>, RunError> {
+    let input = S::parse(input)?;
+
+    let parts = match part {
+        Some(p) => vec![p],
+        None => Part::ALL.to_vec(),
+    };
+
+    parts.into_iter()
+        .map(|part| {
+            let start = Instant::now();
+            let value = match part {
+                Part::One => S::part_1_with(&input, params).map(|a| a.to_string()),
+                Part::Two => S::part_2_with(&input, params).map(|a| a.to_string()),
+            };
+            let elapsed = start.elapsed();
+
+            let value = value.map_err(|e| RunError::Solve(part, e))?;
+
+            Ok(Answer { day: S::DAY, part, value, elapsed })
+        })
+        .collect()
+}
+
+/// Runs a day on the file at `path`, or stdin if the path is "-", recording the file in any parse
+/// error.
+pub fn run_path(
+    runner: Runner,
+    path: &Path,
+    part: Option<Part>,
+    params: &Params,
+) -> Result<Vec<Answer>, RunError> {
+    parse::parse_path(path, |input| Ok(runner(input, part, params)))?
+        .map_err(|e| e.in_file(path))
+}
+
+pub fn print_answers(day: u8, answers: &[Answer]) {
+    println!("*-*-*-*-*- Day {:02} -*-*-*-*-*\n", day);
+
+    for answer in answers {
+        // multi-line answers (e.g. rendered letters) start on their own line.
+        if answer.value.contains('\n') {
+            println!("Answer to part {}:\n{}", answer.part.number(), answer.value);
+        } else {
+            println!("Answer to part {}: {}", answer.part.number(), answer.value);
+        }
+    }
+}
+
+/// Standalone entry point for a day's binary: solves both parts of the file given as the first
+/// argument ("-" for stdin), or of `input.txt` in the current directory.
+pub fn main<S: Solution>() {
+    let path = env::args_os().nth(1).map_or_else(|| PathBuf::from("input.txt"), PathBuf::from);
+
+    let params = Params::defaults(S::PARAMS);
+
+    match run_path(run::<S>, &path, None, &params) {
+        Ok(answers) => print_answers(S::DAY, &answers),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        },
+    }
+}
@@ -0,0 +1,151 @@
+//! Constants a day's solution depends on, such as how many steps to simulate, which can be
+//! overridden to explore beyond the puzzle's own values.
+
+use crate::SolveError;
+
+use std::convert::TryFrom;
+
+/// A constant declared by a day, e.g. the number of days to simulate the lanternfish for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    /// The puzzle's own value.
+    pub default: u64,
+    /// The smallest value the solution can work with.
+    pub min: u64,
+    /// The largest value the solution can work with in reasonable time and memory.
+    pub max: u64,
+    pub about: &'static str,
+}
+
+/// A value for each of a day's params.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    values: Vec<(&'static str, u64)>,
+}
+
+impl Params {
+    pub fn defaults(params: &[Param]) -> Self {
+        Self { values: params.iter().map(|p| (p.name, p.default)).collect() }
+    }
+
+    /// The defaults with some overridden by settings like "steps=1000", which must each name one
+    /// of `params`.
+    pub fn parse(params: &[Param], settings: &[String]) -> Result<Self, String> {
+        let mut result = Self::defaults(params);
+
+        for setting in settings {
+            let (name, value) = setting.split_once('=')
+                .ok_or_else(|| format!("expected a param as name=value, found '{}'", setting))?;
+
+            let param = params.iter()
+                .find(|p| p.name == name)
+                .ok_or_else(|| unknown(name, params))?;
+            let value = value.parse::<u64>()
+                .map_err(|_| format!("expected a whole number for {}, found '{}'", name, value))?;
+
+            if value < param.min {
+                return Err(format!("{} must be at least {}, found {}", name, param.min, value));
+            }
+            if value > param.max {
+                return Err(format!("{} must be at most {}, found {}", name, param.max, value));
+            }
+
+            for (n, v) in &mut result.values {
+                if *n == name {
+                    *v = value;
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// The value of a param. Panics if the day does not declare it.
+    pub fn get(&self, name: &str) -> u64 {
+        match self.values.iter().find(|(n, _)| *n == name) {
+            Some((_, value)) => *value,
+            None => panic!("no param named '{}'", name),
+        }
+    }
+
+    /// The value of a param as the type the solution works with, or an error if it does not fit.
+    /// Panics if the day does not declare it.
+    pub fn try_get<T: TryFrom<u64>>(&self, name: &str) -> Result<T, SolveError> {
+        let value = self.get(name);
+
+        T::try_from(value).map_err(|_| SolveError::new(format!("{} of {} is too large", name, value)))
+    }
+}
+
+fn unknown(name: &str, params: &[Param]) -> String {
+    if params.is_empty() {
+        return format!("unknown param '{}', as this day has none", name);
+    }
+
+    let known = params.iter()
+        .map(|p| format!("  {} (default {}): {}", p.name, p.default, p.about))
+        .collect::<Vec<String>>();
+
+    format!("unknown param '{}', expected one of:\n{}", name, known.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: &[Param] = &[
+        Param { name: "steps", default: 100, min: 0, max: 1000, about: "steps to simulate" },
+        Param { name: "factor", default: 5, min: 1, max: u64::MAX, about: "how many times larger" },
+    ];
+
+    fn settings(settings: &[&str]) -> Vec<String> {
+        settings.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let params = Params::parse(PARAMS, &settings(&["steps=1000"])).unwrap();
+        assert_eq!(1000, params.get("steps"));
+        assert_eq!(5, params.get("factor"));
+        assert_eq!(Params::defaults(PARAMS), Params::parse(PARAMS, &[]).unwrap());
+
+        assert_eq!(
+            Err("expected a param as name=value, found 'steps'".to_string()),
+            Params::parse(PARAMS, &settings(&["steps"]))
+        );
+        assert_eq!(
+            Err("expected a whole number for steps, found '-1'".to_string()),
+            Params::parse(PARAMS, &settings(&["steps=-1"]))
+        );
+        assert_eq!(
+            Err("factor must be at least 1, found 0".to_string()),
+            Params::parse(PARAMS, &settings(&["factor=0"]))
+        );
+        assert_eq!(
+            Err("steps must be at most 1000, found 1001".to_string()),
+            Params::parse(PARAMS, &settings(&["steps=1001"]))
+        );
+        assert_eq!(
+            Err("unknown param 'days', expected one of:\n  steps (default 100): steps to \
+                 simulate\n  factor (default 5): how many times larger"
+                .to_string()),
+            Params::parse(PARAMS, &settings(&["days=3"]))
+        );
+        assert_eq!(
+            Err("unknown param 'days', as this day has none".to_string()),
+            Params::parse(&[], &settings(&["days=3"]))
+        );
+    }
+
+    #[test]
+    fn test_try_get() {
+        let params = Params::parse(PARAMS, &settings(&["factor=300"])).unwrap();
+        assert_eq!(Ok(100u8), params.try_get("steps"));
+        assert_eq!(Ok(300u32), params.try_get("factor"));
+        assert_eq!(
+            Err(SolveError::new("factor of 300 is too large")),
+            params.try_get::<u8>("factor")
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// An error encountered while reading or parsing puzzle input, with the position it was found at.
+#[derive(Debug)]
+pub struct ParseError {
+    file: Option<PathBuf>,
+    line: usize,
+    column: usize,
+    kind: ParseErrorKind,
+}
+
+#[derive(Debug)]
+pub enum ParseErrorKind {
+    Io(io::Error),
+    /// What was expected, and what was found instead if anything.
+    Expected(String, Option<String>),
+}
+
+impl ParseError {
+    /// An error at the given line and column (both counting from 1).
+    pub fn expected(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        Self { file: None, line, column, kind: ParseErrorKind::Expected(expected.into(), None) }
+    }
+
+    pub fn io(err: io::Error) -> Self {
+        Self { file: None, line: 0, column: 0, kind: ParseErrorKind::Io(err) }
+    }
+
+    pub fn found(mut self, found: impl Into<String>) -> Self {
+        if let ParseErrorKind::Expected(_, f) = &mut self.kind {
+            *f = Some(found.into());
+        }
+        self
+    }
+
+    /// Records the file the error was found in, unless already known.
+    pub fn in_file(mut self, path: &Path) -> Self {
+        if self.file.is_none() {
+            self.file = Some(path.to_path_buf());
+        }
+        self
+    }
+
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    /// Line the error was found on, counting from 1, or 0 if it is not tied to a line.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Column the error was found at, counting from 1, or 0 if it is not tied to a column.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut position = vec![];
+        if let Some(file) = &self.file {
+            position.push(file.display().to_string());
+        }
+        if self.line > 0 {
+            position.push(self.line.to_string());
+        }
+        if self.column > 0 {
+            position.push(self.column.to_string());
+        }
+        if !position.is_empty() {
+            write!(f, "{}: ", position.join(":"))?;
+        }
+
+        match &self.kind {
+            ParseErrorKind::Io(err) => write!(f, "{}", err),
+            ParseErrorKind::Expected(expected, None) => write!(f, "expected {}", expected),
+            ParseErrorKind::Expected(expected, Some(found)) => {
+                write!(f, "expected {}, found {:?}", expected, found)
+            },
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::Io(err) => Some(err),
+            ParseErrorKind::Expected(..) => None,
+        }
+    }
+}
+
+impl From<io::Error> for ParseError {
+    fn from(err: io::Error) -> Self {
+        Self::io(err)
+    }
+}
+
+/// A line of puzzle input along with its line number (counting from 1), so that errors can point
+/// at the offending part of it.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Column (counting from 1) at which `part`, a slice of this line, starts.
+    pub fn column_of(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|offset| *offset <= self.text.len())
+            .unwrap_or(0);
+
+        self.text[..offset].chars().count() + 1
+    }
+
+    /// Column just past the end of the line.
+    pub fn end(&self) -> usize {
+        self.text.chars().count() + 1
+    }
+
+    /// An error pointing at `part`, a slice of this line.
+    pub fn error(&self, part: &str, expected: impl Into<String>) -> ParseError {
+        let err = ParseError::expected(self.number, self.column_of(part), expected);
+
+        if part.is_empty() {
+            err
+        } else {
+            err.found(part)
+        }
+    }
+
+    /// An error pointing at the given column.
+    pub fn error_at(&self, column: usize, expected: impl Into<String>) -> ParseError {
+        ParseError::expected(self.number, column, expected)
+    }
+
+    /// Parses `part`, a slice of this line.
+    pub fn parse<T: FromStr>(&self, part: &str, expected: &str) -> Result<T, ParseError> {
+        part.parse::<T>().map_err(|_| self.error(part, expected))
+    }
+
+    /// Splits the line around the first occurrence of `delimiter`.
+    pub fn split_once(&self, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(delimiter)
+            .ok_or_else(|| self.error_at(self.end(), format!("{:?}", delimiter)))
+    }
+}
+
+/// Reads every line of `input`.
+pub fn read_lines(input: impl BufRead) -> Result<Vec<String>, ParseError> {
+    input.lines()
+        .enumerate()
+        .map(|(i, l)| l.map_err(|e| ParseError { line: i + 1, ..ParseError::io(e) }))
+        .collect()
+}
+
+/// Numbers `lines` from 1.
+pub fn numbered(lines: &[String]) -> impl Iterator<Item = Line<'_>> {
+    lines.iter()
+        .enumerate()
+        .map(|(i, text)| Line { number: i + 1, text })
+}
+
+/// Reads every line of `input` and parses them.
+pub fn parse_lines<T, F>(input: impl BufRead, parse: F) -> Result<T, ParseError>
+where
+    F: FnOnce(&[String]) -> Result<T, ParseError>,
+{
+    let lines = read_lines(input)?;
+
+    parse(&lines)
+}
+
+/// Opens the file at `path` and parses it, recording the file in any error. A path of "-" reads
+/// stdin instead.
+pub fn parse_path<T, F>(path: &Path, parse: F) -> Result<T, ParseError>
+where
+    F: FnOnce(&mut dyn BufRead) -> Result<T, ParseError>,
+{
+    if path == Path::new("-") {
+        return parse_stdin(parse);
+    }
+
+    let file = File::open(path).map_err(|e| ParseError::io(e).in_file(path))?;
+
+    parse(&mut BufReader::new(file)).map_err(|e| e.in_file(path))
+}
+
+/// Parses everything piped in on stdin.
+pub fn parse_stdin<T, F>(parse: F) -> Result<T, ParseError>
+where
+    F: FnOnce(&mut dyn BufRead) -> Result<T, ParseError>,
+{
+    parse(&mut io::stdin().lock())
+}
+
+/// The error for input that ended before something that was expected.
+pub fn unexpected_end(lines: &[String], expected: impl Into<String>) -> ParseError {
+    let line = lines.len().max(1);
+    let column = lines.last().map_or(0, |l| l.chars().count()) + 1;
+
+    ParseError::expected(line, column, expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_position() {
+        let line = Line { number: 3, text: "forward x" };
+        let (_, val) = line.split_once(" ").unwrap();
+
+        let err = line.parse::<u32>(val, "a number").unwrap_err();
+        assert_eq!(3, err.line());
+        assert_eq!(9, err.column());
+        assert_eq!("3:9: expected a number, found \"x\"", err.to_string());
+
+        let err = err.in_file(Path::new("input.txt"));
+        assert_eq!("input.txt:3:9: expected a number, found \"x\"", err.to_string());
+    }
+
+    #[test]
+    fn test_missing_delimiter() {
+        let line = Line { number: 1, text: "0,9" };
+        let err = line.split_once(" -> ").unwrap_err();
+        assert_eq!("1:4: expected \" -> \"", err.to_string());
+    }
+
+    #[test]
+    fn test_io_error() {
+        let err = parse_path(Path::new("no_such_input.txt"), |input| read_lines(input)).unwrap_err();
+        assert_eq!(Some(Path::new("no_such_input.txt")), err.file());
+        assert!(matches!(err.kind(), ParseErrorKind::Io(_)));
+    }
+}
@@ -0,0 +1,156 @@
+use crate::parse::ParseError;
+use crate::{Part, Solution};
+
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long both parts may take between them on any input a parser accepts, even in a debug build.
+pub const SOLVE_BUDGET: Duration = Duration::from_secs(10);
+
+/// Why a part has no answer for an input that parsed fine, e.g. scanners that cannot all be
+/// located.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolveError {
+    message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into() }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for SolveError {}
+
+/// An error running a day on an input: either the input did not parse, or a part could not be
+/// solved for it.
+#[derive(Debug)]
+pub enum RunError {
+    Parse(ParseError),
+    Solve(Part, SolveError),
+}
+
+impl RunError {
+    /// Records the file the input was read from in a parse error, unless already known.
+    pub fn in_file(self, path: &Path) -> Self {
+        match self {
+            RunError::Parse(err) => RunError::Parse(err.in_file(path)),
+            err => err,
+        }
+    }
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RunError::Parse(err) => write!(f, "{}", err),
+            RunError::Solve(part, err) => write!(f, "part {}: {}", part.number(), err),
+        }
+    }
+}
+
+impl Error for RunError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RunError::Parse(err) => Some(err),
+            RunError::Solve(_, err) => Some(err),
+        }
+    }
+}
+
+impl From<ParseError> for RunError {
+    fn from(err: ParseError) -> Self {
+        RunError::Parse(err)
+    }
+}
+
+/// Solves both parts on a thread of their own, failing if either panics or they take longer than
+/// `budget` between them. For fuzzing, where whatever a parser accepts must be solved in good
+/// time, if only with a `SolveError`. A part that runs over is left running.
+pub fn solve_within<S>(input: S::Input, budget: Duration) -> Result<(), String>
+where
+    S: Solution + 'static,
+    S::Input: Send,
+{
+    let (solved, finished) = mpsc::channel();
+
+    thread::spawn(move || {
+        let _ = S::part_1(&input);
+        let _ = solved.send(());
+        let _ = S::part_2(&input);
+        let _ = solved.send(());
+    });
+
+    let deadline = Instant::now() + budget;
+
+    for part in [Part::One, Part::Two] {
+        match finished.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(()) => {}
+            Err(RecvTimeoutError::Timeout) => {
+                return Err(format!("part {} is still running after {:?}", part.number(), budget));
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(format!("part {} panicked", part.number()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::BufRead;
+
+    /// Panics in part one when given 1, and takes its time in part two when given 2.
+    struct Moody;
+
+    impl Solution for Moody {
+        const DAY: u8 = 0;
+
+        type Input = u8;
+        type Answer1 = u8;
+        type Answer2 = u8;
+
+        fn parse(_input: impl BufRead) -> Result<u8, ParseError> {
+            Ok(0)
+        }
+
+        fn part_1(input: &u8) -> Result<u8, SolveError> {
+            assert_ne!(1, *input);
+            Ok(*input)
+        }
+
+        fn part_2(input: &u8) -> Result<u8, SolveError> {
+            if *input == 2 {
+                thread::sleep(Duration::from_secs(1));
+            }
+            Ok(*input)
+        }
+    }
+
+    #[test]
+    fn test_solve_within() {
+        let budget = Duration::from_millis(100);
+
+        assert_eq!(Ok(()), solve_within::<Moody>(0, budget));
+        assert_eq!(Err("part 1 panicked".to_string()), solve_within::<Moody>(1, budget));
+        assert_eq!(
+            Err("part 2 is still running after 100ms".to_string()),
+            solve_within::<Moody>(2, budget)
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use aoc_2021_common::generate::rng;
+use rand::Rng;
+
+/// Number of measurements in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 2000;
+
+/// `size` depth measurements, drifting mostly deeper like a real sonar sweep.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let mut depth: i32 = rng.gen_range(100..300);
+    let mut result = String::new();
+
+    for _ in 0..size {
+        result.push_str(&format!("{}\n", depth));
+        depth = (depth + rng.gen_range(-10..=20)).max(0);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day01::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day01::part_1(&input).unwrap();
+            Day01::part_2(&input).unwrap();
+        }
+    }
+}
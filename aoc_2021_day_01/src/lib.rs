@@ -0,0 +1,96 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines};
+use aoc_2021_common::{ParseError, Solution, SolveError};
+
+use std::io::BufRead;
+
+/// Day 1: Sonar Sweep.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parser(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(solve_part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(solve_part_2(input))
+    }
+}
+
+/// Parses one depth measurement per line.
+pub fn parser(input: impl BufRead) -> Result<Vec<i32>, ParseError> {
+    parse_lines(input, |lines| {
+        numbered(lines)
+            .map(|l| l.parse::<i32>(l.text, "a depth measurement"))
+            .collect()
+    })
+}
+
+/// Counts the measurements that are larger than the previous one.
+pub fn solve_part_1(input: &[i32]) -> i32 {
+    input.windows(2)
+        .fold(0, |acc, win| {
+            if win[1] > win[0] {
+                acc + 1
+            } else {
+                acc
+            }
+    })
+}
+
+/// Counts the three-measurement sliding windows whose sum is larger than the previous window.
+pub fn solve_part_2(input: &[i32]) -> i32 {
+    // summed wider, as three measurements can add up to more than an `i32` holds.
+    input.windows(3)
+        .map(|win| win.iter().map(|&depth| i64::from(depth)).sum())
+        .collect::<Vec<i64>>()
+        .windows(2)
+        .fold(0, |acc, win| {
+            if win[1] > win[0] {
+                acc + 1
+            } else {
+                acc
+            }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        let input = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve_part_1(&input);
+        assert_eq!(7, res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve_part_2(&input);
+        assert_eq!(5, res);
+    }
+
+    #[test]
+    fn test_part_2_extremes() {
+        let input = parser("2147483647\n2147483647\n2147483647\n".as_bytes()).unwrap();
+        assert_eq!(0, solve_part_2(&input));
+
+        let input = parser("-2147483648\n2147483647\n2147483647\n2147483647\n".as_bytes()).unwrap();
+        assert_eq!(1, solve_part_2(&input));
+    }
+}
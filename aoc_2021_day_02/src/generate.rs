@@ -0,0 +1,41 @@
+use aoc_2021_common::generate::rng;
+use rand::Rng;
+
+/// Number of instructions in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// `size` instructions, moving down more often than up so the submarine stays below the surface.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let mut result = String::new();
+
+    for _ in 0..size {
+        let dir = match rng.gen_range(0..10) {
+            0..=4 => "forward",
+            5..=7 => "down",
+            _ => "up",
+        };
+        result.push_str(&format!("{} {}\n", dir, rng.gen_range(1..=9)));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day02::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day02::part_1(&input).unwrap();
+            Day02::part_2(&input).unwrap();
+        }
+    }
+}
@@ -0,0 +1,164 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines};
+use aoc_2021_common::{ParseError, Solution, SolveError};
+
+use std::io::BufRead;
+use std::str::FromStr;
+
+/// Day 2: Dive!
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Instruction>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parser(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        solve_part_1(input).ok_or_else(too_far)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        solve_part_2(input).ok_or_else(too_far)
+    }
+}
+
+fn too_far() -> SolveError {
+    SolveError::new("the submarine goes too far to keep track of")
+}
+
+struct Position {
+    depth: i64,
+    horizontal: i64,
+    aim: i64,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Instruction {
+    pub dir: Direction,
+    pub val: u32,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    Forward,
+    Down,
+    Up,
+}
+
+impl FromStr for Direction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "forward" => Ok(Direction::Forward),
+            "down" => Ok(Direction::Down),
+            "up" => Ok(Direction::Up),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Parses one instruction per line, e.g. "forward 5".
+pub fn parser(input: impl BufRead) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, |lines| {
+        numbered(lines)
+            .map(|l| {
+                let (dir, val) = l.split_once(" ")?;
+
+                let dir = l.parse::<Direction>(dir, "a direction (forward, down or up)")?;
+                let val = l.parse::<u32>(val, "a magnitude")?;
+
+                Ok(Instruction { dir, val })
+            })
+            .collect()
+    })
+}
+
+/// Final depth multiplied by horizontal position, treating up/down as moving the submarine, or
+/// `None` if any of them grow too large for an `i64`.
+pub fn solve_part_1(input: &[Instruction]) -> Option<i64> {
+    let start = Position { depth: 0, horizontal: 0, aim: 0 };
+
+    let position = input.iter().try_fold(start, |mut pos, instr| {
+        let val = i64::from(instr.val);
+
+        match instr.dir {
+            Direction::Forward => pos.horizontal = pos.horizontal.checked_add(val)?,
+            Direction::Down => pos.depth = pos.depth.checked_add(val)?,
+            Direction::Up => pos.depth = pos.depth.checked_sub(val)?,
+        }
+
+        Some(pos)
+    })?;
+
+    position.depth.checked_mul(position.horizontal)
+}
+
+/// Final depth multiplied by horizontal position, treating up/down as changing the aim, or `None`
+/// if any of them grow too large for an `i64`.
+pub fn solve_part_2(input: &[Instruction]) -> Option<i64> {
+    let start = Position { depth: 0, horizontal: 0, aim: 0 };
+
+    let position = input.iter().try_fold(start, |mut pos, instr| {
+        let val = i64::from(instr.val);
+
+        match instr.dir {
+            Direction::Forward => {
+                pos.horizontal = pos.horizontal.checked_add(val)?;
+                pos.depth = pos.depth.checked_add(val.checked_mul(pos.aim)?)?;
+            },
+            Direction::Down => pos.aim = pos.aim.checked_add(val)?,
+            Direction::Up => pos.aim = pos.aim.checked_sub(val)?,
+        }
+
+        Some(pos)
+    })?;
+
+    position.depth.checked_mul(position.horizontal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        let input = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve_part_1(&input);
+        assert_eq!(Some(150), res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve_part_2(&input);
+        assert_eq!(Some(900), res);
+    }
+
+    #[test]
+    fn test_too_far() {
+        // past what an i32 holds, but not an i64.
+        let input = parser("forward 4294967295\ndown 3\n".as_bytes()).unwrap();
+        assert_eq!(Some(12884901885), solve_part_1(&input));
+        assert_eq!(Some(0), solve_part_2(&input));
+
+        let input = parser("forward 4294967295\ndown 4294967295\n".as_bytes()).unwrap();
+        assert_eq!(
+            Err(SolveError::new("the submarine goes too far to keep track of")),
+            Day02::part_1(&input)
+        );
+
+        let input = "down 4294967295\nforward 4294967295\nforward 4294967295\n";
+        let input = parser(input.as_bytes()).unwrap();
+        assert_eq!(None, solve_part_2(&input));
+    }
+}
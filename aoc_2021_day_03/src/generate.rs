@@ -0,0 +1,46 @@
+use aoc_2021_common::generate::rng;
+use rand::Rng;
+
+/// Number of diagnostic values in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// Width of each diagnostic value, as in the real puzzle input.
+const BIT_WIDTH: usize = 12;
+
+/// At least two 12 bit diagnostic values, including both a 0 and a 1 in the leading bit so that
+/// neither life support rating runs out of candidates.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let mut result = String::new();
+
+    for i in 0..size.max(2) {
+        let mut value: u32 = rng.gen_range(0..1 << BIT_WIDTH);
+        match i {
+            0 => value &= !(1 << (BIT_WIDTH - 1)),
+            1 => value |= 1 << (BIT_WIDTH - 1),
+            _ => (),
+        }
+        result.push_str(&format!("{:0width$b}\n", value, width = BIT_WIDTH));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day03;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day03::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day03::part_1(&input).unwrap();
+            Day03::part_2(&input).unwrap();
+        }
+    }
+}
@@ -0,0 +1,160 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines, unexpected_end};
+use aoc_2021_common::{ParseError, Solution, SolveError};
+
+use std::io::BufRead;
+
+/// Day 3: Binary Diagnostic.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = (Vec<u32>, u8);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parser(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        let (values, bit_width) = input;
+        Ok(solve_part_1(values, *bit_width as usize))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        let (values, bit_width) = input;
+        solve_part_2(values, *bit_width)
+    }
+}
+
+/// Returns the binary diagnostic values and their bit width.
+pub fn parser(input: impl BufRead) -> Result<(Vec<u32>, u8), ParseError> {
+    parse_lines(input, |lines| {
+        let first = lines.first().ok_or_else(|| unexpected_end(lines, "a binary number"))?;
+        let bit_width = first.len();
+
+        let values = numbered(lines)
+            .map(|l| {
+                if let Some(col) = l.text.find(|c| c != '0' && c != '1') {
+                    return Err(l.error_at(col + 1, "a binary digit"));
+                }
+                if l.text.is_empty() || l.text.len() > 32 {
+                    return Err(l.error_at(1, "a binary number of 1 to 32 bits"));
+                }
+                if l.text.len() != bit_width {
+                    return Err(l.error_at(1, format!("a binary number of {} bits", bit_width)));
+                }
+
+                Ok(l.text.chars().fold(0, |acc, c| (acc << 1) + c.to_digit(2).unwrap()))
+            })
+            .collect::<Result<Vec<u32>, ParseError>>()?;
+
+        Ok((values, bit_width as u8))
+    })
+}
+
+/// Power consumption: gamma rate multiplied by epsilon rate.
+pub fn solve_part_1(input: &[u32], bit_width: usize) -> u64 {
+    let threshold = input.len() as u32 / 2;
+
+    let mut freq_vec = vec![0; bit_width];
+
+    for value in input.iter() {
+        for (i, freq) in freq_vec.iter_mut().enumerate() {
+            if value & 1 << i != 0 {
+                *freq += 1;
+            }
+        }
+    }
+
+    let (mut gamma, mut epsilon) = (0u64, 0u64);
+
+    for (i, freq) in freq_vec.into_iter().enumerate() {
+        if freq > threshold {
+            gamma += 1 << i;
+        } else {
+            epsilon += 1 << i;
+        }
+    }
+
+    gamma * epsilon
+}
+
+/// Life support rating: oxygen generator rating multiplied by CO2 scrubber rating.
+pub fn solve_part_2(input: &[u32], bit_width: u8) -> Result<u64, SolveError> {
+    let oxygen = rating(input, bit_width, |zeros, ones| ones >= zeros)?;
+    let co2 = rating(input, bit_width, |zeros, ones| ones < zeros)?;
+
+    Ok(oxygen as u64 * co2 as u64)
+}
+
+/// Filters `input` bit by bit from the most significant, keeping the values with a 1 wherever
+/// `keep_ones` says so given how many values have a 0 and a 1 there, until a single value is left.
+/// Where the values left all agree on a bit there is nothing to filter.
+fn rating(
+    input: &[u32],
+    bit_width: u8,
+    keep_ones: impl Fn(usize, usize) -> bool,
+) -> Result<u32, SolveError> {
+    let mut values = input.to_vec();
+
+    for i in (0..bit_width).rev() {
+        if values.len() == 1 {
+            break;
+        }
+
+        let (p0, p1): (Vec<u32>, Vec<u32>) = values.iter().partition(|&val| val & 0b1 << i == 0);
+
+        if !p0.is_empty() && !p1.is_empty() {
+            values = if keep_ones(p0.len(), p1.len()) { p1 } else { p0 };
+        }
+    }
+
+    match values[..] {
+        [value] => Ok(value),
+        _ => Err(SolveError::new(format!(
+            "{} values are left after filtering on every bit",
+            values.len()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        let (input, bit_width) = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve_part_1(&input, bit_width as usize);
+        assert_eq!(198, res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let (input, bit_width) = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve_part_2(&input, bit_width);
+        assert_eq!(Ok(230), res);
+    }
+
+    #[test]
+    fn test_part_2_single_value() {
+        let (input, bit_width) = parser("1\n".as_bytes()).unwrap();
+        assert_eq!(Ok(1), solve_part_2(&input, bit_width));
+    }
+
+    #[test]
+    fn test_part_2_shared_bits() {
+        let (input, bit_width) = parser("101\n101\n".as_bytes()).unwrap();
+        let err = solve_part_2(&input, bit_width).unwrap_err();
+        assert_eq!("2 values are left after filtering on every bit", err.to_string());
+
+        let (input, bit_width) = parser("10\n11\n".as_bytes()).unwrap();
+        assert_eq!(Ok(6), solve_part_2(&input, bit_width));
+    }
+}
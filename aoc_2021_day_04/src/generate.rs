@@ -0,0 +1,52 @@
+use crate::Board;
+
+use aoc_2021_common::generate::rng;
+use rand::seq::SliceRandom;
+
+/// Number of boards in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 100;
+
+/// Numbers from 0 up to this are drawn, as in the real puzzle input.
+const NUMBERS: u32 = 100;
+
+/// Every number drawn once in a random order, followed by `size` boards of distinct numbers.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let mut numbers = (0..NUMBERS).collect::<Vec<u32>>();
+
+    numbers.shuffle(&mut rng);
+    let mut result = numbers.iter().map(|n| n.to_string()).collect::<Vec<String>>().join(",");
+    result.push('\n');
+
+    for _ in 0..size {
+        numbers.shuffle(&mut rng);
+        result.push('\n');
+
+        for row in numbers[..Board::DIMENSION * Board::DIMENSION].chunks(Board::DIMENSION) {
+            let row = row.iter().map(|n| format!("{:>2}", n)).collect::<Vec<String>>();
+            result.push_str(&row.join(" "));
+            result.push('\n');
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day04;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day04::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day04::part_1(&input).unwrap();
+            Day04::part_2(&input).unwrap();
+        }
+    }
+}
@@ -0,0 +1,302 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines, unexpected_end, Line};
+use aoc_2021_common::{ParseError, Part, Solution, SolveError};
+use ansi_term::Colour::{Red};
+use tracing::{debug, trace};
+
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+use std::io::BufRead;
+
+/// Day 4: Giant Squid.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = (Vec<u32>, Vec<Board>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parser(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        let (nums, boards) = input;
+        Ok(solve(Part::One, nums, boards.clone()))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        let (nums, boards) = input;
+        Ok(solve(Part::Two, nums, boards.clone()))
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "BoardData", try_from = "BoardData"))]
+pub struct Board {
+    grid: [[u32; Self::DIMENSION]; Self::DIMENSION],
+    unmarked: HashMap<u32, Position>, // unmarked values and their (row, col) position.
+    marked_totals: HashMap<usize, usize>, // total marked in each row and col.
+    has_won: bool,
+}
+
+/// A board as serialized: just its numbers, which are checked to differ and from which the marks
+/// are rebuilt when loaded.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct BoardData {
+    grid: [[u32; Board::DIMENSION]; Board::DIMENSION],
+}
+
+#[cfg(feature = "serde")]
+impl From<Board> for BoardData {
+    fn from(board: Board) -> Self {
+        Self { grid: board.grid }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<BoardData> for Board {
+    type Error = String;
+
+    fn try_from(data: BoardData) -> Result<Self, String> {
+        let mut seen = HashSet::new();
+
+        match data.grid.iter().flatten().find(|n| !seen.insert(**n)) {
+            Some(n) => Err(format!("{} appears more than once on a board", n)),
+            None => Ok(Board::new(data.grid)),
+        }
+    }
+}
+
+impl std::fmt::Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for row in self.grid {
+            for v in row {
+                if self.unmarked.contains_key(&v) {
+                    write!(f, "{:>2} ", v)?;
+                } else {
+                    write!(f, "{:>2} ", Red.bold().paint(format!("{}", v)))?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Board {
+    pub const DIMENSION: usize = 5;
+
+    /// Builds an unmarked board from its rows of numbers, which must all differ.
+    pub fn new(grid: [[u32; Self::DIMENSION]; Self::DIMENSION]) -> Self {
+        let mut marked_totals = HashMap::new();
+        for i in 0..(Self::DIMENSION * 2) {
+            marked_totals.insert(i, 0);
+        }
+
+        let mut unmarked = HashMap::new();
+        for (row_idx, row) in grid.iter().enumerate() {
+            for (col_idx, val) in row.iter().enumerate() {
+                unmarked.insert(*val, Position::new(row_idx, col_idx));
+            }
+        }
+
+        Self { grid, unmarked, marked_totals, has_won: false }
+    }
+
+    /// Returns the score if the board wins, else None.
+    pub fn mark(&mut self, n: u32) -> Option<u64> {
+        if let Some(pos) = self.unmarked.remove(&n) {
+            trace!(number = n, row = pos.row, col = pos.col, "mark");
+
+            if let Some(row_mrks) = self.marked_totals.get_mut(&pos.row) {
+                *row_mrks += 1;
+            }
+
+            if let Some(col_mrks) = self.marked_totals.get_mut(&(pos.col + Self::DIMENSION)) {
+                *col_mrks += 1;
+            }
+
+            if self.marked_totals[&pos.row] == Self::DIMENSION
+                || self.marked_totals[&(pos.col + Self::DIMENSION)] == Self::DIMENSION
+            {
+                self.has_won = true;
+                let unmarked_sum = self.unmarked.keys().map(|v| u64::from(*v)).sum::<u64>();
+                let score = u64::from(n) * unmarked_sum;
+                debug!(number = n, score, "board won");
+                return Some(score);
+            }
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Position {
+    row: usize,
+    col: usize,
+}
+
+impl Position {
+    fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+}
+
+/// The largest number that may be drawn or appear on a board, so that no score overflows a `u64`.
+pub const MAX_NUMBER: u32 = 1_000_000;
+
+/// Parses `val`, a slice of `l`, as a number from 0 to `MAX_NUMBER`.
+fn parse_number(l: &Line, val: &str, expected: &str) -> Result<u32, ParseError> {
+    let expected = format!("{} from 0 to {}", expected, MAX_NUMBER);
+
+    match l.parse::<u32>(val, &expected)? {
+        n if n <= MAX_NUMBER => Ok(n),
+        _ => Err(l.error(val, &expected)),
+    }
+}
+
+/// Returns the drawn numbers and the bingo boards.
+pub fn parser(input: impl BufRead) -> Result<(Vec<u32>, Vec<Board>), ParseError> {
+    parse_lines(input, |lines| {
+        let mut lines_iter = numbered(lines);
+
+        let drawn_nums = {
+            let l = lines_iter.next().ok_or_else(|| unexpected_end(lines, "the drawn numbers"))?;
+            l.text.split_terminator(',')
+                .map(|v| parse_number(&l, v, "a drawn number"))
+                .collect::<Result<Vec<u32>, ParseError>>()?
+        };
+
+        let mut boards = vec![];
+        let mut board_lines = vec![];
+
+        for l in lines_iter {
+            if l.text.is_empty() {
+                if board_lines.is_empty() {
+                    continue;
+                }
+
+                boards.push(parse_board(&board_lines)?);
+
+                board_lines.clear();
+            } else {
+                board_lines.push(l);
+            }
+        }
+        if !board_lines.is_empty() {
+            boards.push(parse_board(&board_lines)?);
+        }
+
+        Ok((drawn_nums, boards))
+    })
+}
+
+fn parse_board(lines: &[Line]) -> Result<Board, ParseError> {
+    let mut grid = [[0; Board::DIMENSION]; Board::DIMENSION];
+    let mut seen = HashSet::new();
+
+    for (row_idx, l) in lines.iter().enumerate() {
+        if row_idx == Board::DIMENSION {
+            return Err(l.error_at(1, format!("a blank line after {} board rows", Board::DIMENSION)));
+        }
+
+        let vals = l.text.split_whitespace().collect::<Vec<&str>>();
+        if vals.len() != Board::DIMENSION {
+            return Err(l.error_at(1, format!("{} numbers per board row", Board::DIMENSION)));
+        }
+
+        for (col_idx, val) in vals.into_iter().enumerate() {
+            let n = parse_number(l, val, "a board number")?;
+
+            // numbers are marked by value, so each may appear only once.
+            if !seen.insert(n) {
+                return Err(l.error(val, "a number not already on the board"));
+            }
+
+            grid[row_idx][col_idx] = n;
+        }
+    }
+
+    if lines.len() < Board::DIMENSION {
+        let next_line = lines.last().map_or(1, |l| l.number + 1);
+        return Err(ParseError::expected(
+            next_line, 1, format!("{} rows per board", Board::DIMENSION)
+        ));
+    }
+
+    Ok(Board::new(grid))
+}
+
+/// Returns the score of the first board to win for part one, or the last board to win for part
+/// two.
+pub fn solve(part: Part, nums: &[u32], mut boards: Vec<Board>) -> u64 {
+    let mut result = 0;
+    'outer: for n in nums.iter() {
+        for b in boards.iter_mut() {
+            if b.has_won { continue; }
+            if let Some(score) = b.mark(*n) {
+                result = score;
+                if part == Part::One {
+                    break 'outer;
+                }
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        let (nums, boards) = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(Part::One, &nums, boards);
+        assert_eq!(4512, res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let (nums, boards) = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(Part::Two, &nums, boards);
+        assert_eq!(1924, res);
+    }
+
+    #[test]
+    fn test_repeated_number() {
+        let input = TEST_INPUT.replacen("22 13 17 11  0", "22 13 17 11 22", 1);
+        let err = parser(input.as_bytes()).unwrap_err();
+        assert_eq!(
+            "3:13: expected a number not already on the board, found \"22\"",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_largest_numbers() {
+        // the first row wins on the largest number, with the largest numbers left unmarked.
+        let mut board = "1 2 3 4 1000000\n".to_string();
+        for row in 0..4 {
+            let row = (0..5).map(|col| (999_980 + row * 5 + col).to_string()).collect::<Vec<_>>();
+            board.push_str(&format!("{}\n", row.join(" ")));
+        }
+        let (nums, boards) = parser(format!("1,2,3,4,1000000\n\n{}", board).as_bytes()).unwrap();
+        assert_eq!(19_999_790_000_000, solve(Part::One, &nums, boards));
+
+        let err = parser(format!("1000001\n\n{}", board).as_bytes()).unwrap_err();
+        assert_eq!(
+            "1:1: expected a drawn number from 0 to 1000000, found \"1000001\"",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use aoc_2021_common::generate::rng;
+use rand::Rng;
+
+/// Number of lines of vents in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 500;
+
+/// Coordinates lie below this, as in the real puzzle input.
+const EXTENT: u32 = 1000;
+
+/// `size` horizontal, vertical and diagonal lines of vents.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let mut result = String::new();
+
+    for _ in 0..size {
+        let (x1, y1) = (rng.gen_range(0..EXTENT), rng.gen_range(0..EXTENT));
+
+        let (x2, y2) = match rng.gen_range(0..3) {
+            0 => (rng.gen_range(0..EXTENT), y1),
+            1 => (x1, rng.gen_range(0..EXTENT)),
+            _ => {
+                let (right, up) = (rng.gen_bool(0.5), rng.gen_bool(0.5));
+                let room_x = if right { EXTENT - 1 - x1 } else { x1 };
+                let room_y = if up { EXTENT - 1 - y1 } else { y1 };
+                let d = rng.gen_range(0..=room_x.min(room_y));
+
+                (
+                    if right { x1 + d } else { x1 - d },
+                    if up { y1 + d } else { y1 - d },
+                )
+            },
+        };
+
+        result.push_str(&format!("{},{} -> {},{}\n", x1, y1, x2, y2));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day05;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day05::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day05::part_1(&input).unwrap();
+            Day05::part_2(&input).unwrap();
+        }
+    }
+}
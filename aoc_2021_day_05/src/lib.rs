@@ -0,0 +1,209 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines};
+use aoc_2021_common::{ParseError, Point2, Solution, SolveError};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use std::collections::HashMap;
+use std::io::BufRead;
+
+/// Day 5: Hydrothermal Venture.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Vec<Line>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parser(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(solve_part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(solve_part_2(input))
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Line {
+    pub fn is_horizontal_or_vertical(&self) -> bool {
+        self.start.x == self.end.x || self.start.y == self.end.y
+    }
+
+    /// Every point covered by the line, from start to end. Lines are horizontal, vertical or at 45
+    /// degrees.
+    pub fn points_intersected(&self) -> Vec<Point> {
+        let mut result = vec![];
+
+        let x_range: Vec<u32> = {
+            if self.start.x > self.end.x {
+                (self.end.x..=self.start.x).rev().collect()
+            } else {
+                (self.start.x..=self.end.x).collect()
+            }
+        };
+        let y_range: Vec<u32> = {
+            if self.start.y > self.end.y {
+                (self.end.y..=self.start.y).rev().collect()
+            } else {
+                (self.start.y..=self.end.y).collect()
+            }
+        };
+
+        if self.start.y == self.end.y {
+            for x in x_range {
+                result.push(Point::new(x, self.start.y));
+            }
+        } else if self.start.x == self.end.x {
+            for y in y_range {
+                result.push(Point::new(self.start.x, y));
+            }
+        } else {
+            for (x, y) in x_range.into_iter().zip(y_range) {
+                result.push(Point::new(x, y));
+            }
+        }
+
+        result
+    }
+}
+
+pub type Point = Point2<u32>;
+
+/// The furthest a vent may lie along either axis, as the solver visits every point of every line.
+pub const MAX_COORDINATE: u32 = 10_000;
+
+/// Parses one line of vents per line, e.g. "0,9 -> 5,9".
+pub fn parser(input: impl BufRead) -> Result<Vec<Line>, ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"(?x)
+            ^
+            (?P<start_x>\d+)
+            ,
+            (?P<start_y>\d+)
+            \x20->\x20
+            (?P<end_x>\d+)
+            ,
+            (?P<end_y>\d+)
+            $
+        ").unwrap();
+    }
+
+    parse_lines(input, |lines| {
+        let mut result = vec![];
+
+        for line in numbered(lines) {
+            let caps = RE.captures(line.text)
+                .ok_or_else(|| line.error(line.text, "a line of vents, e.g. \"0,9 -> 5,9\""))?;
+            let expected = format!("a coordinate from 0 to {}", MAX_COORDINATE);
+            let coord = |name| match line.parse::<u32>(&caps[name], &expected)? {
+                c if c <= MAX_COORDINATE => Ok(c),
+                _ => Err(line.error(&caps[name], &expected)),
+            };
+
+            let l = Line {
+                start: Point::new(coord("start_x")?, coord("start_y")?),
+                end: Point::new(coord("end_x")?, coord("end_y")?),
+            };
+
+            let (dx, dy) = (l.start.x.abs_diff(l.end.x), l.start.y.abs_diff(l.end.y));
+            if dx != 0 && dy != 0 && dx != dy {
+                return Err(line.error(line.text, "a horizontal, vertical or diagonal line"));
+            }
+
+            result.push(l);
+        }
+
+        Ok(result)
+    })
+}
+
+/// Number of points where horizontal and vertical lines overlap.
+pub fn solve_part_1(lines: &[Line]) -> u32 {
+    let mut map = HashMap::new();
+
+    for line in lines.iter().filter(|l| l.is_horizontal_or_vertical()) {
+        for pt in line.points_intersected() {
+            if let Some(total) = map.get_mut(&pt) {
+                *total = 1;
+            } else {
+                map.insert(pt, 0);
+            }
+        }
+    }
+
+    map.values().sum()
+}
+
+/// Number of points where any lines overlap.
+pub fn solve_part_2(lines: &[Line]) -> u32 {
+    let mut map = HashMap::new();
+
+    for line in lines.iter() {
+        for pt in line.points_intersected() {
+            if let Some(total) = map.get_mut(&pt) {
+                *total = 1;
+            } else {
+                map.insert(pt, 0);
+            }
+        }
+    }
+
+    map.values().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_points_intersected() {
+        let l1 = Line {
+            start: Point::new(0, 9),
+            end: Point::new(5, 9),
+        };
+
+        let intersected = l1.points_intersected();
+        for i in 0..=5 {
+            assert_eq!(Point::new(i, 9), intersected[i as usize]);
+        }
+    }
+
+    #[test]
+    fn test_part_1() {
+        let lines = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve_part_1(&lines);
+        assert_eq!(5, res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let lines = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve_part_2(&lines);
+        assert_eq!(12, res);
+    }
+
+    #[test]
+    fn test_coordinate_bounds() {
+        let lines = parser("0,0 -> 10000,10000\n10000,0 -> 0,10000\n".as_bytes()).unwrap();
+        assert_eq!(1, solve_part_2(&lines));
+
+        let err = parser("0,0 -> 1000000000,1000000000\n".as_bytes()).unwrap_err();
+        assert_eq!("1:8: expected a coordinate from 0 to 10000, found \"1000000000\"", err.to_string());
+    }
+}
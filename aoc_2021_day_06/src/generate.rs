@@ -0,0 +1,32 @@
+use aoc_2021_common::generate::rng;
+use rand::Rng;
+
+/// Number of lanternfish in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 300;
+
+/// The timers of `size` lanternfish, which start out between 1 and 5 like those in the puzzle.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let timers = (0..size).map(|_| rng.gen_range(1..=5).to_string()).collect::<Vec<String>>();
+
+    format!("{}\n", timers.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day06::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day06::part_1(&input).unwrap();
+            Day06::part_2(&input).unwrap();
+        }
+    }
+}
@@ -0,0 +1,127 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines, unexpected_end};
+use aoc_2021_common::{Param, Params, ParseError, Solution, SolveError};
+
+use std::io::BufRead;
+
+/// Day 6: Lanternfish.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "days_1",
+            default: 80,
+            min: 0,
+            max: 10_000,
+            about: "days to simulate for part one",
+        },
+        Param {
+            name: "days_2",
+            default: 256,
+            min: 0,
+            max: 10_000,
+            about: "days to simulate for part two",
+        },
+    ];
+
+    type Input = Vec<usize>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parser(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Self::part_1_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Self::part_2_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part_1_with(input: &Self::Input, params: &Params) -> Result<Self::Answer1, SolveError> {
+        solve(input, params.try_get("days_1")?).ok_or_else(too_many_fish)
+    }
+
+    fn part_2_with(input: &Self::Input, params: &Params) -> Result<Self::Answer2, SolveError> {
+        solve(input, params.try_get("days_2")?).ok_or_else(too_many_fish)
+    }
+}
+
+fn too_many_fish() -> SolveError {
+    SolveError::new("there are too many lanternfish to count")
+}
+
+/// Parses the comma separated timers of the initial lanternfish.
+pub fn parser(input: impl BufRead) -> Result<Vec<usize>, ParseError> {
+    parse_lines(input, |lines| {
+        let l = numbered(lines).next().ok_or_else(|| unexpected_end(lines, "the lanternfish timers"))?;
+
+        l.text.split_terminator(',')
+            .map(|x| {
+                match l.parse::<usize>(x, "a timer from 0 to 8")? {
+                    timer if timer <= 8 => Ok(timer),
+                    _ => Err(l.error(x, "a timer from 0 to 8")),
+                }
+            })
+            .collect()
+    })
+}
+
+/// Total number of lanternfish after the given number of days, or `None` if there are too many to
+/// count.
+pub fn solve(initial_generation: &[usize], days: i64) -> Option<i64> {
+    // number of fish at each stage of the lifecycle.
+    let mut fish = [0i64; 9];
+    
+    for stage in initial_generation {
+        fish[*stage] += 1;
+    }
+
+    for _ in 0..days {
+        fish.rotate_left(1);
+        // spawn
+        fish[6] = fish[6].checked_add(fish[8])?;
+    }
+
+    fish.iter().try_fold(0i64, |total, n| total.checked_add(*n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        let initial_fish = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(&initial_fish, 80);
+        assert_eq!(Some(5934), res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let initial_fish = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(&initial_fish, 256);
+        assert_eq!(Some(26984457539), res);
+    }
+
+    #[test]
+    fn test_params() {
+        let initial_fish = parser(TEST_INPUT.as_bytes()).unwrap();
+        let params = Params::parse(Day06::PARAMS, &["days_1=18".to_string()]).unwrap();
+
+        assert_eq!(26, Day06::part_1_with(&initial_fish, &params).unwrap());
+        assert_eq!(26984457539, Day06::part_2_with(&initial_fish, &params).unwrap());
+
+        let params = Params::parse(Day06::PARAMS, &["days_2=1000".to_string()]).unwrap();
+        assert!(Day06::part_2_with(&initial_fish, &params).is_err());
+        assert!(Params::parse(Day06::PARAMS, &["days_2=10001".to_string()]).is_err());
+    }
+}
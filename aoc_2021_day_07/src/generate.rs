@@ -0,0 +1,34 @@
+use aoc_2021_common::generate::rng;
+use rand::Rng;
+
+/// Number of crabs in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// The positions of `size` crabs, spread over 0 to `size`.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let positions = (0..size.max(1))
+        .map(|_| rng.gen_range(0..=size).to_string())
+        .collect::<Vec<String>>();
+
+    format!("{}\n", positions.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day07;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day07::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day07::part_1(&input).unwrap();
+            Day07::part_2(&input).unwrap();
+        }
+    }
+}
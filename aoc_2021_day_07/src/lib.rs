@@ -0,0 +1,133 @@
+pub mod generate;
+pub mod reference;
+
+use aoc_2021_common::parse::{numbered, parse_lines, unexpected_end};
+use aoc_2021_common::{ParseError, Solution, SolveError};
+
+use std::collections::HashMap;
+use std::io::BufRead;
+
+/// Day 7: The Treachery of Whales.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<i32>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parser(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(solve(true, input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(solve(false, input))
+    }
+}
+
+/// The furthest position a crab may start from, which the solver steps through one by one.
+pub const MAX_POSITION: i32 = 100_000;
+
+/// Parses the comma separated horizontal positions of the crabs.
+pub fn parser(input: impl BufRead) -> Result<Vec<i32>, ParseError> {
+    parse_lines(input, |lines| {
+        let l = numbered(lines).next().ok_or_else(|| unexpected_end(lines, "the crab positions"))?;
+        let expected = format!("a position from 0 to {}", MAX_POSITION);
+
+        l.text.split_terminator(',')
+            .map(|x| {
+                match l.parse::<i32>(x, &expected)? {
+                    pos if (0..=MAX_POSITION).contains(&pos) => Ok(pos),
+                    _ => Err(l.error(x, &expected)),
+                }
+            })
+            .collect()
+    })
+}
+
+/// Least fuel needed to align every crab. Each step costs 1 fuel for part one, and one more than
+/// the previous step for part two.
+pub fn solve(part_1: bool, initial_positions: &[i32]) -> i64 {
+    let mut freq_map = HashMap::new();
+
+    for p in initial_positions {
+        if let Some(freq) = freq_map.get_mut(p) {
+            *freq += 1;
+        } else {
+            freq_map.insert(p, 1);
+        }
+    }
+
+    // wide enough for every crab to step the furthest they can.
+    let total_crabs = initial_positions.len() as i64;
+    let sum_of_initial_positions: i64 = initial_positions.iter().map(|x| i64::from(*x)).sum();
+
+    // initially set based on moving to position 0.
+    let mut fuel_usage = if part_1 {
+        sum_of_initial_positions
+    } else {
+        initial_positions.iter().map(|x| i64::from(*x)).fold(0, |acc, x| acc + x * (x + 1) / 2)
+    };
+
+    let mut pos = 0;
+    let mut static_crabs = freq_map.get(&pos).unwrap_or(&0);
+    let mut fuel_delta: i64 = if part_1 {
+        total_crabs - 2 * static_crabs
+    } else {
+        sum_of_initial_positions - static_crabs
+    };
+
+    while fuel_delta > 0 {
+        fuel_usage -= fuel_delta;
+
+        pos += 1;
+        static_crabs = freq_map.get(&pos).unwrap_or(&0);
+
+        if part_1 {
+            fuel_delta -= 2 * static_crabs;
+        } else {
+            fuel_delta -= total_crabs + static_crabs;
+        }
+    }
+
+    fuel_usage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        let initial_positions = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(true, &initial_positions);
+        assert_eq!(37, res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let initial_positions = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(false, &initial_positions);
+        assert_eq!(168, res);
+    }
+
+    #[test]
+    fn test_furthest() {
+        let positions = parser("0,100000,100000".as_bytes()).unwrap();
+        assert_eq!(100_000, solve(true, &positions));
+        assert_eq!(3_333_400_000, solve(false, &positions));
+
+        let err = parser("0,100001".as_bytes()).unwrap_err();
+        assert_eq!("1:3: expected a position from 0 to 100000, found \"100001\"", err.to_string());
+
+        let err = parser("309301,5".as_bytes()).unwrap_err();
+        assert_eq!("1:1: expected a position from 0 to 100000, found \"309301\"", err.to_string());
+    }
+}
@@ -0,0 +1,46 @@
+//! A slow but obviously correct solver, to check the fast one against.
+
+/// Least fuel needed to align every crab, trying every position between the outermost crabs.
+pub fn solve(part_1: bool, positions: &[i32]) -> i64 {
+    let cost = |distance: i32| -> i64 {
+        if part_1 {
+            distance.into()
+        } else {
+            (1..=distance.into()).sum()
+        }
+    };
+
+    let (min, max) = (positions.iter().min().unwrap(), positions.iter().max().unwrap());
+
+    (*min..=*max)
+        .map(|target| positions.iter().map(|p| cost((p - target).abs())).sum())
+        .min()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_example() {
+        let positions = crate::parser(TEST_INPUT.as_bytes()).unwrap();
+        assert_eq!(37, solve(true, &positions));
+        assert_eq!(168, solve(false, &positions));
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_solver(seed in any::<u64>(), size in 1..50usize) {
+            let positions = crate::parser(generate(seed, size).as_bytes()).unwrap();
+
+            prop_assert_eq!(solve(true, &positions), crate::solve(true, &positions));
+            prop_assert_eq!(solve(false, &positions), crate::solve(false, &positions));
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use aoc_2021_common::generate::rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Number of displays in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 200;
+
+/// The segments lit for each digit on a correctly wired display.
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// `size` displays, each with its wires randomly mixed up.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let mut result = String::new();
+
+    for _ in 0..size {
+        let mut wiring = ('a'..='g').collect::<Vec<char>>();
+        wiring.shuffle(&mut rng);
+
+        let mut patterns = (0..10).map(|d| pattern(&mut rng, &wiring, d)).collect::<Vec<String>>();
+        patterns.shuffle(&mut rng);
+
+        let output = (0..4)
+            .map(|_| {
+                let digit = rng.gen_range(0..10);
+                pattern(&mut rng, &wiring, digit)
+            })
+            .collect::<Vec<String>>();
+
+        result.push_str(&format!("{} | {}\n", patterns.join(" "), output.join(" ")));
+    }
+
+    result
+}
+
+/// The signal pattern that displays `digit` through `wiring`, with its wires in a random order.
+fn pattern(rng: &mut StdRng, wiring: &[char], digit: usize) -> String {
+    let mut wires = DIGITS[digit]
+        .bytes()
+        .map(|segment| wiring[(segment - b'a') as usize])
+        .collect::<Vec<char>>();
+    wires.shuffle(rng);
+
+    wires.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day08;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day08::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day08::part_1(&input).unwrap();
+            Day08::part_2(&input).unwrap();
+        }
+    }
+}
@@ -0,0 +1,230 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines, Line};
+use aoc_2021_common::{ParseError, Solution, SolveError};
+
+use std::collections::HashMap;
+use std::io::BufRead;
+
+/// Day 8: Seven Segment Search.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Entry>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parser(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        solve(true, input).ok_or_else(cannot_be_decoded)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        solve(false, input).ok_or_else(cannot_be_decoded)
+    }
+}
+
+/// For entries whose patterns are not the ten digits, which the parser rules out.
+fn cannot_be_decoded() -> SolveError {
+    SolveError::new("a display's signal patterns cannot be decoded")
+}
+
+/// The ten signal patterns and the four output digits of a display, encoded with
+/// `convert_to_bits`.
+pub type Entry = (Vec<u8>, Vec<u8>);
+
+/// Counts the 1, 4, 7 and 8 digits in the outputs for part one, or sums the decoded output values
+/// for part two. `None` if some entry's patterns cannot be decoded.
+pub fn solve(part_1: bool, lines: &[Entry]) -> Option<u32> {
+    let mut result: u32 = 0;
+
+    for line in lines {
+        let (patterns, output) = line;
+
+        let decoder = analyse_patterns(patterns)?;
+
+        for (i, code) in output.iter().rev().enumerate() {
+            let val = *decoder.get(code).unwrap_or(&0);
+            if part_1 {
+                if val == 1 || val == 4 || val == 7 || val == 8 {
+                    result += 1;
+                }
+            } else {
+                result += val as u32 * 10_u32.pow(i as u32);
+            }
+        }
+    }
+
+    Some(result)
+}
+
+// the first 7 bits correspond to the presence of characters a-g.
+pub fn convert_to_bits(input: &str) -> u8 {
+    let mut result = 0;
+
+    for c in input.chars() {
+        result |= 1 << (c as u8 - b'a');
+    }
+
+    result
+}
+
+/// Parses one entry per line, e.g. "acedgfb cdfbe ... ab | cdfeb fcadb cdfeb cdbaf".
+pub fn parser(input: impl BufRead) -> Result<Vec<Entry>, ParseError> {
+    parse_lines(input, |lines| {
+        numbered(lines)
+            .map(|l| {
+                let (patterns, output) = l.split_once(" | ")?;
+
+                let patterns = parse_digits(&l, patterns, 10)?;
+                if analyse_patterns(&patterns).is_none() {
+                    return Err(l.error_at(1, "ten signal patterns showing the digits 0 to 9"));
+                }
+
+                let output = parse_digits(&l, output, 4)?;
+
+                Ok((patterns, output))
+            })
+            .collect()
+    })
+}
+
+/// Parses `count` whitespace separated signal patterns from `part`, a slice of the line `l`.
+fn parse_digits(l: &Line, part: &str, count: usize) -> Result<Vec<u8>, ParseError> {
+    let digits = part.split_whitespace()
+        .map(|s| {
+            let is_valid = s.chars().all(|c| ('a'..='g').contains(&c))
+                && convert_to_bits(s).count_ones() as usize == s.len();
+
+            if is_valid {
+                Ok(convert_to_bits(s))
+            } else {
+                Err(l.error(s, "a signal pattern of distinct wires a to g"))
+            }
+        })
+        .collect::<Result<Vec<u8>, ParseError>>()?;
+
+    if digits.len() != count {
+        return Err(l.error_at(l.column_of(part), format!("{} signal patterns", count)));
+    }
+
+    Ok(digits)
+}
+
+/// Maps each of the ten encoded signal patterns to the digit it displays, or `None` if they are
+/// not ten patterns that can be told apart as the digits.
+pub fn analyse_patterns(patterns: &[u8]) -> Option<HashMap<u8, u8>> {
+    let mut decoder: HashMap<u8, u8> = HashMap::new();
+    let mut encoder: HashMap<u8, u8> = HashMap::new();
+    let mut num_segments_map: HashMap<u32, Vec<u8>> = HashMap::new();
+
+    for p in patterns {
+        let num_segments = p.count_ones();
+
+        let digit = match num_segments {
+            2 => 1,
+            3 => 7,
+            4 => 4,
+            7 => 8,
+            _ => {
+                num_segments_map.entry(num_segments).or_default().push(*p);
+                continue;
+            },
+        };
+        decoder.insert(*p, digit);
+        encoder.insert(digit, *p);
+    }
+
+    // 5 segments
+    let mut five_segments = num_segments_map.remove(&5)?;
+    // 6 segments
+    let mut six_segments = num_segments_map.remove(&6)?;
+    if five_segments.len() != 3 || six_segments.len() != 3 {
+        return None;
+    }
+
+    // find 2, then 5, which leaves 3.
+    let p2 = take_completing(&mut five_segments, *encoder.get(&4)?)?;
+    let p5 = take_completing(&mut five_segments, p2)?;
+    decoder.insert(p2, 2);
+    decoder.insert(p5, 5);
+    decoder.insert(five_segments[0], 3);
+
+    // find 0, then 6, which leaves 9.
+    let p0 = take_completing(&mut six_segments, p5)?;
+    let p6 = take_completing(&mut six_segments, *encoder.get(&1)?)?;
+    decoder.insert(p0, 0);
+    decoder.insert(p6, 6);
+    decoder.insert(six_segments[0], 9);
+
+    // ten patterns, each given a different digit.
+    if patterns.len() != 10 || decoder.len() != 10 || encoder.len() != 4 {
+        return None;
+    }
+
+    Some(decoder)
+}
+
+/// Removes and returns the only pattern among `candidates` that lights every segment when shown
+/// together with `other`.
+fn take_completing(candidates: &mut Vec<u8>, other: u8) -> Option<u8> {
+    let completing = |p: &u8| p | other == 127;
+
+    if candidates.iter().filter(|p| completing(p)).count() != 1 {
+        return None;
+    }
+    let i = candidates.iter().position(completing)?;
+
+    Some(candidates.remove(i))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        let lines = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(true, &lines);
+        assert_eq!(Some(26), res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let lines = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(false, &lines);
+        assert_eq!(Some(61229), res);
+    }
+
+    #[test]
+    fn test_undecodable() {
+        // two patterns for 1 and none for 7.
+        let input = TEST_INPUT.lines().next().unwrap().replace(" edb |", " eb |");
+        let err = parser(input.as_bytes()).unwrap_err();
+        assert_eq!("1:1: expected ten signal patterns showing the digits 0 to 9", err.to_string());
+
+        // two patterns of four wires, and only two of five.
+        let input = "cbafeg ebfcd gdeafb cg ceg egaf cedbfag gdeacf gbac cbfeg | gdfcae bafcge \
+            efadbg afbdgce";
+        let err = parser(input.as_bytes()).unwrap_err();
+        assert_eq!("1:1: expected ten signal patterns showing the digits 0 to 9", err.to_string());
+    }
+
+    #[test]
+    fn test_repeated_wires() {
+        let input = "dfecgagacbg gbafce adfceb cg eacbgdf defbg bacfe gfc gbcef | gc ecfgb fcg \
+            acbg";
+        let err = parser(input.as_bytes()).unwrap_err();
+        assert_eq!(
+            "1:1: expected a signal pattern of distinct wires a to g, found \"dfecgagacbg\"",
+            err.to_string()
+        );
+    }
+}
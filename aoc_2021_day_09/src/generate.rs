@@ -0,0 +1,38 @@
+use aoc_2021_common::generate::{char_grid, rng};
+use rand::Rng;
+
+/// Width and height of the real puzzle input.
+pub const DEFAULT_SIZE: usize = 100;
+
+/// A `size` by `size` heightmap, where roughly a quarter of the locations are 9s walling off the
+/// basins.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+
+    char_grid(size.max(1), size.max(1), || {
+        if rng.gen_bool(0.25) {
+            '9'
+        } else {
+            rng.gen_range('0'..='8')
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day09;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day09::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day09::part_1(&input).unwrap();
+            Day09::part_2(&input).unwrap();
+        }
+    }
+}
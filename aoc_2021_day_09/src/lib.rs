@@ -0,0 +1,114 @@
+pub mod generate;
+
+use aoc_2021_common::parse::parse_lines;
+use aoc_2021_common::{Grid, ParseError, Solution, SolveError};
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+/// Day 9: Smoke Basin.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Grid<u8>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parser(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(solve(true, input.clone()))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(solve(false, input.clone()))
+    }
+}
+
+/// Parses the heightmap, one row of digits per line.
+pub fn parser(input: impl BufRead) -> Result<Grid<u8>, ParseError> {
+    parse_lines(input, Grid::parse_digits)
+}
+
+/// Sum of the risk levels of the low points for part one, or the product of the sizes of the three
+/// largest basins for part two.
+pub fn solve(part_1: bool, mut grid: Grid<u8>) -> u32 {
+    let mut result_1 = 0;
+
+    let mut min_heap = BinaryHeap::new();
+
+    for (x, y) in grid.positions() {
+        if part_1 {
+            if degree(&grid, x, y) == 0 {
+                result_1 += 1 + grid[(x, y)] as u32;
+            }
+        } else {
+            if grid[(x, y)] != 9 {
+                let basin_size = dfs_basin_size(&mut grid, x, y);
+
+                min_heap.push(Reverse(basin_size));
+
+                while min_heap.len() > 3 {
+                    min_heap.pop();
+                }
+            }
+        }
+    }
+
+    if part_1 {
+        result_1
+    } else {
+        min_heap.iter().map(|v| v.0).product()
+    }
+}
+
+fn dfs_basin_size(grid: &mut Grid<u8>, x: usize, y: usize) -> u32 {
+    if grid[(x, y)] == 9 {
+        return 0;
+    }
+
+    let mut size = 1;
+
+    grid[(x, y)] = 9;
+
+    for (nbr_x, nbr_y) in grid.neighbours_4(x, y).collect::<Vec<_>>() {
+        size += dfs_basin_size(grid, nbr_x, nbr_y);
+    }
+
+    size
+}
+
+/// Number of neighbours no higher than the given location.
+fn degree(grid: &Grid<u8>, x: usize, y: usize) -> u8 {
+    let v = grid[(x, y)];
+
+    grid.neighbours_4(x, y)
+        .filter(|nbr| v >= grid[*nbr])
+        .count() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        let grid = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(true, grid);
+        assert_eq!(15, res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let grid = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(false, grid);
+        assert_eq!(1134, res);
+    }
+}
@@ -0,0 +1,73 @@
+use aoc_2021_common::generate::rng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Number of lines in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 100;
+
+const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// How deeply chunks nest, which keeps autocompletion scores within a u64.
+const MAX_DEPTH: usize = 20;
+
+/// `size` lines, each either corrupted by a mismatched closing bracket or left incomplete. The
+/// first line is always incomplete, so there is a middle autocompletion score to find.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let mut result = String::new();
+
+    for i in 0..size.max(1) {
+        let mut line = String::new();
+        let mut open = vec![];
+
+        for _ in 0..rng.gen_range(10..100) {
+            if open.is_empty() || (open.len() < MAX_DEPTH && rng.gen_bool(0.55)) {
+                let (opening, closing) = *PAIRS.choose(&mut rng).unwrap();
+                line.push(opening);
+                open.push(closing);
+            } else {
+                line.push(open.pop().unwrap());
+            }
+        }
+        if open.is_empty() {
+            line.push('(');
+            open.push(')');
+        }
+
+        if i > 0 && rng.gen_bool(0.5) {
+            let expected = *open.last().unwrap();
+            let wrong = PAIRS.iter().map(|(_, c)| *c).filter(|c| *c != expected).collect::<Vec<char>>();
+            line.push(*wrong.choose(&mut rng).unwrap());
+
+            // whatever follows the first mismatch is never looked at.
+            for _ in 0..rng.gen_range(0..10) {
+                let (opening, closing) = *PAIRS.choose(&mut rng).unwrap();
+                line.push(if rng.gen_bool(0.5) { opening } else { closing });
+            }
+        }
+
+        result.push_str(&line);
+        result.push('\n');
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day10;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day10::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day10::part_1(&input).unwrap();
+            Day10::part_2(&input).unwrap();
+        }
+    }
+}
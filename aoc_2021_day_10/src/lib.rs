@@ -0,0 +1,163 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines};
+use aoc_2021_common::{ParseError, Solution, SolveError};
+use phf::phf_map;
+
+use std::io::BufRead;
+
+static CHARACTER_MAP: phf::Map<char, char> = phf_map! {
+    '(' => ')',
+    '[' => ']',
+    '{' => '}',
+    '<' => '>',
+};
+static SCORE_MAP_1: phf::Map<char, u64> = phf_map! {
+    ')' => 3,
+    ']' => 57,
+    '}' => 1197,
+    '>' => 25137,
+};
+static SCORE_MAP_2: phf::Map<char, u64> = phf_map! {
+    ')' => 1,
+    ']' => 2,
+    '}' => 3,
+    '>' => 4,
+};
+
+/// Day 10: Syntax Scoring.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Vec<char>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parser(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        solve(true, input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        solve(false, input)
+    }
+}
+
+/// Parses each line of the navigation subsystem into its characters.
+pub fn parser(input: impl BufRead) -> Result<Vec<Vec<char>>, ParseError> {
+    parse_lines(input, |lines| {
+        numbered(lines)
+            .map(|line| {
+                for (i, c) in line.text.char_indices() {
+                    if !CHARACTER_MAP.contains_key(&c) && !SCORE_MAP_1.contains_key(&c) {
+                        return Err(line.error(&line.text[i..i + c.len_utf8()], "a bracket"));
+                    }
+                }
+
+                Ok(line.text.chars().collect())
+            })
+            .collect()
+    })
+}
+
+/// Syntax error score of the corrupted lines for part one, or the middle autocompletion score of
+/// the incomplete lines for part two. Fails for part two if no line is incomplete, or a score is too
+/// large to count.
+pub fn solve(part_1: bool, lines: &Vec<Vec<char>>) -> Result<u64, SolveError> {
+    let mut result = 0;
+    let mut scores = vec![];
+    let mut stack = vec![];
+
+    'outer_loop: for l in lines {
+        stack.clear();
+
+        // check all characters in line are valid
+        for c in l {
+            if let Some(closing_char) = CHARACTER_MAP.get(c) {
+                stack.push(*closing_char);
+            } else {
+                if let Some(expected_char) = stack.pop() {
+                    // discard corrupt lines.
+                    if expected_char != *c {
+                        if part_1 {
+                            result += SCORE_MAP_1.get(c).unwrap();
+                        }
+                        continue 'outer_loop;
+                    }
+                }
+            }
+        }
+
+        // auto-completion score incomplete lines
+        if !part_1 {
+            let score = stack.iter()
+                .rev()
+                .try_fold(0u64, |acc, c| acc.checked_mul(5)?.checked_add(SCORE_MAP_2[c]))
+                .ok_or_else(|| SolveError::new("an autocompletion score is too large to count"))?;
+
+            scores.push(score);
+        }
+    }
+
+    if part_1 {
+        Ok(result)
+    } else {
+        scores.sort();
+        scores.get(scores.len() / 2)
+            .copied()
+            .ok_or_else(|| SolveError::new("no line is incomplete"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        let lines = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(true, &lines).unwrap();
+        assert_eq!(26397, res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let lines = parser(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(false, &lines).unwrap();
+        assert_eq!(288957, res);
+    }
+
+    #[test]
+    fn test_uneven_lines() {
+        // two lines corrupted by a ']', and three incomplete ones.
+        let input = "{}()<<<><()({<()><>}[(<>)<>])<[{}[]]>><>>{<>[]}{[(){<>}]}>(){{}}<>[]([])[]\
+            [{}({[{}\n\
+            []{{<<(){[{}[()[(({}<<((){{}()}){}>()({{}]\n\
+            [(<>)]{}([(<><<>><<[]()>>{})])[]([<><>{()[[<[{}]([[{}{{[<()>]({{}})[()][[([[{((<])>}]\
+            <[}[<\n\
+            ({{}})<<{<>{<[<>]>[]}{}}>>[]{}<()[[]]{{({[<({})>]<>})<><>[[]][<>][]{}(()){[\n\
+            <<>[(<><>[])<(([[]<>()])([()\n";
+        let lines = parser(input.as_bytes()).unwrap();
+        assert_eq!(Ok(114), solve(true, &lines));
+        assert_eq!(Ok(1719), solve(false, &lines));
+    }
+
+    #[test]
+    fn test_unscorable() {
+        let lines = parser("{([(<{}[<>[]}>{[]{[(<()>\n".as_bytes()).unwrap();
+        assert_eq!(Err(SolveError::new("no line is incomplete")), solve(false, &lines));
+
+        let lines = parser("<".repeat(28).as_bytes()).unwrap();
+        assert_eq!(
+            Err(SolveError::new("an autocompletion score is too large to count")),
+            solve(false, &lines)
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use crate::step_once;
+
+use aoc_2021_common::generate::rng;
+use aoc_2021_common::Grid;
+use rand::Rng;
+
+/// Width and height of the real puzzle input.
+pub const DEFAULT_SIZE: usize = 10;
+
+/// Most steps a candidate grid may take to synchronise.
+const MAX_STEPS: usize = 1000;
+
+/// Most random grids to try before settling for one that is synchronised from the start.
+const MAX_ATTEMPTS: usize = 1000;
+
+/// A `size` by `size` grid of energy levels on which every octopus eventually flashes at once, as
+/// part two needs. Many random grids never synchronise, so candidates are simulated and retried.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let size = size.max(1);
+
+    for _ in 0..MAX_ATTEMPTS {
+        let grid = Grid::from_fn(size, size, |_, _| rng.gen_range(0..=9));
+        if synchronises(&grid) {
+            return format!("{}\n", grid);
+        }
+    }
+
+    format!("{}\n", Grid::new(size, size, rng.gen_range(0..=9)))
+}
+
+fn synchronises(grid: &Grid<u8>) -> bool {
+    let mut grid = grid.clone();
+
+    (0..MAX_STEPS).any(|_| step_once(&mut grid) == grid.len() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day11;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 10), generate(1, 10));
+
+        for seed in 0..20 {
+            let input = Day11::parse(generate(seed, 10).as_bytes()).unwrap();
+            Day11::part_1(&input).unwrap();
+            Day11::part_2(&input).unwrap();
+        }
+    }
+}
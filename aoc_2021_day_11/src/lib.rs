@@ -0,0 +1,143 @@
+pub mod generate;
+
+use aoc_2021_common::parse::parse_lines;
+use aoc_2021_common::{Grid, Param, Params, ParseError, Solution, SolveError};
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::io::BufRead;
+
+/// Day 11: Dumbo Octopus.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "steps",
+        default: 100,
+        min: 1,
+        max: 1_000_000,
+        about: "steps to count flashes over for part one",
+    }];
+
+    type Input = Grid<u8>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Self::part_1_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        solve(false, input.clone(), 0)
+            .ok_or_else(|| SolveError::new("the octopuses never all flash at once"))
+    }
+
+    fn part_1_with(input: &Self::Input, params: &Params) -> Result<Self::Answer1, SolveError> {
+        Ok(solve(true, input.clone(), params.try_get("steps")?).unwrap_or_default())
+    }
+}
+
+/// Parses the octopus energy levels, one row of digits per line.
+pub fn parse(input: impl BufRead) -> Result<Grid<u8>, ParseError> {
+    parse_lines(input, Grid::parse_digits)
+}
+
+/// Total flashes after `steps` steps for part one, or the first step on which every octopus
+/// flashes for part two, which ignores `steps`. For part two, `None` if that never happens, as the
+/// octopuses come back round to how they were after an earlier step.
+pub fn solve(part_1: bool, mut grid: Grid<u8>, steps: u32) -> Option<u64> {
+    let mut result = 0;
+    // hashes rather than whole grids, to keep memory down when they take long to repeat.
+    let mut seen = HashSet::new();
+
+    for step in 1.. {
+        let total_flashed = step_once(&mut grid);
+
+        if part_1 {
+            result += u64::from(total_flashed);
+            if step == steps {
+                return Some(result);
+            }
+        } else {
+            if total_flashed == grid.len() as u32 {
+                return Some(u64::from(step));
+            }
+
+            let mut hasher = DefaultHasher::new();
+            grid.hash(&mut hasher);
+            if !seen.insert(hasher.finish()) {
+                return None;
+            }
+        }
+    }
+
+    Some(result)
+}
+
+/// Advances every octopus by a single step, returning how many flashed.
+pub fn step_once(grid: &mut Grid<u8>) -> u32 {
+    for (x, y) in grid.positions() {
+        process(x, y, grid);
+    }
+
+    let mut total_flashed = 0;
+
+    for (_, energy) in grid.iter_mut() {
+        if *energy == 10 {
+            total_flashed += 1;
+            *energy = 0;
+        }
+    }
+
+    total_flashed
+}
+
+fn process(x: usize, y: usize, grid: &mut Grid<u8>) {
+    if grid[(x, y)] == 10 { return; } // already flashed.
+
+    grid[(x, y)] += 1;
+
+    if grid[(x, y)] <= 9 { return; } // hasn't flashed.
+
+    for (nbr_x, nbr_y) in grid.neighbours_8(x, y).collect::<Vec<_>>() {
+        process(nbr_x, nbr_y, grid);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        let grid = parse(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(true, grid, 100);
+        assert_eq!(Some(1656), res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let grid = parse(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(false, grid, 0);
+        assert_eq!(Some(195), res);
+    }
+
+    #[test]
+    fn test_never_all_flash() {
+        let grid = parse("002\n".as_bytes()).unwrap();
+        assert_eq!(None, solve(false, grid, 0));
+
+        let grid = parse("99046\n39477\n83022\n98203\n76273\n".as_bytes()).unwrap();
+        assert_eq!(Some(412), solve(true, grid.clone(), 100));
+        assert_eq!(None, solve(false, grid, 0));
+    }
+}
@@ -0,0 +1,76 @@
+use aoc_2021_common::generate::rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use std::collections::HashSet;
+
+/// Number of caves besides start and end in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 10;
+
+/// A cave system of `size` caves besides start and end, about a quarter of them big. Big caves are
+/// never connected to each other, as the paths through them would never end.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+
+    let big = (0..size / 4).map(|i| name(i, 'A')).collect::<Vec<String>>();
+    let small = (0..size - big.len()).map(|i| name(i, 'a')).collect::<Vec<String>>();
+    let caves = big.iter().chain(small.iter()).collect::<Vec<&String>>();
+
+    let mut edges = vec![];
+    let mut seen = HashSet::new();
+    let mut add = |a: &str, b: &str, rng: &mut StdRng| {
+        if a != b && seen.insert((a.min(b).to_string(), a.max(b).to_string())) {
+            // either way round means the same passage.
+            edges.push(if rng.gen_bool(0.5) { format!("{}-{}", a, b) } else { format!("{}-{}", b, a) });
+        }
+    };
+
+    if caves.is_empty() {
+        add("start", "end", &mut rng);
+    }
+    for end in ["start", "end"] {
+        for _ in 0..2 {
+            if let Some(cave) = caves.choose(&mut rng) {
+                add(end, cave, &mut rng);
+            }
+        }
+    }
+    if !small.is_empty() {
+        for cave in big.iter() {
+            for _ in 0..rng.gen_range(2..=4) {
+                add(cave, small.choose(&mut rng).unwrap(), &mut rng);
+            }
+        }
+        for cave in small.iter() {
+            add(cave, small.choose(&mut rng).unwrap(), &mut rng);
+        }
+    }
+
+    edges.shuffle(&mut rng);
+    edges.iter().map(|e| format!("{}\n", e)).collect()
+}
+
+/// A two letter cave name, counting up from "aa" or "AA".
+fn name(i: usize, first: char) -> String {
+    [i / 26 % 26, i % 26].iter().map(|n| (first as u8 + *n as u8) as char).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day12;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 8), generate(1, 8));
+
+        for seed in 0..20 {
+            let input = Day12::parse(generate(seed, 8).as_bytes()).unwrap();
+            Day12::part_1(&input).unwrap();
+            Day12::part_2(&input).unwrap();
+        }
+    }
+}
@@ -0,0 +1,265 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines, unexpected_end};
+use aoc_2021_common::{ParseError, Solution, SolveError};
+
+use std::collections::HashMap;
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+use std::io::BufRead;
+
+/// Day 12: Passage Pathing.
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Graph;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(solve(false, input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(solve(true, input))
+    }
+}
+
+/// Builds the cave graph from one "start-end" edge per line.
+pub fn parse(input: impl BufRead) -> Result<Graph, ParseError> {
+    parse_lines(input, |lines| {
+        let mut g = Graph::new();
+
+        // the search starts from the first vertex.
+        g.insert_node("start");
+
+        for line in numbered(lines) {
+            let (start, end) = line.split_once("-")?;
+
+            for label in [start, end] {
+                if !is_cave_name(label) {
+                    return Err(line.error(label, "a cave name"));
+                }
+            }
+            // the paths would bounce between two big caves forever.
+            if is_big(start) && is_big(end) {
+                return Err(line.error(line.text, "a passage to at least one small cave"));
+            }
+
+            g.insert_edge(line.text);
+        }
+
+        if !g.label_map.contains_key("end") {
+            return Err(unexpected_end(lines, "an edge to the \"end\" cave"));
+        }
+
+        Ok(g)
+    })
+}
+
+fn is_cave_name(label: &str) -> bool {
+    !label.is_empty() && label.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn is_big(label: &str) -> bool {
+    label.starts_with(|c: char| c.is_uppercase())
+}
+
+/// Number of paths from start to end that visit small caves at most once, or, if `can_revisit`,
+/// that may visit a single small cave twice.
+pub fn solve(can_revisit: bool, graph: &Graph) -> u32 {
+    graph.dfs(0, can_revisit)
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "GraphData"))]
+pub struct Graph {
+    adj_list: Vec<Vec<usize>>,
+    label_map: HashMap<String, usize>,
+    vertex_map: HashMap<usize, String>,
+    is_small: Vec<bool>,
+}
+
+/// A graph as serialized, checked to be one the parser could have built before being loaded.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct GraphData {
+    adj_list: Vec<Vec<usize>>,
+    label_map: HashMap<String, usize>,
+    vertex_map: HashMap<usize, String>,
+    is_small: Vec<bool>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<GraphData> for Graph {
+    type Error = String;
+
+    fn try_from(data: GraphData) -> Result<Self, String> {
+        let caves = data.adj_list.len();
+        if [data.label_map.len(), data.vertex_map.len(), data.is_small.len()] != [caves; 3] {
+            return Err(format!("expected {} caves in every map", caves));
+        }
+
+        for (idx, small) in data.is_small.iter().enumerate() {
+            let label = data.vertex_map.get(&idx)
+                .ok_or_else(|| format!("expected a name for cave {}", idx))?;
+
+            if !is_cave_name(label) || data.label_map.get(label) != Some(&idx) {
+                return Err(format!("expected a cave name for cave {}, found {:?}", idx, label));
+            }
+            if *small == is_big(label) {
+                return Err(format!("cave {:?} is marked as the wrong size", label));
+            }
+        }
+        if data.label_map.get("start") != Some(&0) || !data.label_map.contains_key("end") {
+            return Err("expected the start cave first, and an end cave".to_string());
+        }
+
+        for (idx, nbrs) in data.adj_list.iter().enumerate() {
+            for &nbr in nbrs {
+                if nbr >= caves || !data.adj_list[nbr].contains(&idx) {
+                    return Err(format!("expected a passage back from cave {} to cave {}", nbr, idx));
+                }
+                if !data.is_small[idx] && !data.is_small[nbr] {
+                    return Err(format!("caves {} and {} are both big, yet joined", idx, nbr));
+                }
+            }
+        }
+
+        let GraphData { adj_list, label_map, vertex_map, is_small } = data;
+
+        Ok(Self { adj_list, label_map, vertex_map, is_small })
+    }
+}
+
+impl Default for Graph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self {
+            adj_list: vec![],
+            label_map: HashMap::new(),
+            vertex_map: HashMap::new(),
+            is_small: vec![],
+        }
+    }
+
+    /// Inserts an undirected edge given as "start-end", adding any new caves.
+    pub fn insert_edge(&mut self, edge: &str) {
+        let (start_label, end_label) = {
+            let x = edge.split_terminator("-").collect::<Vec<&str>>();
+            (x[0], x[1])
+        };
+
+        let start_idx = self.insert_node(start_label);
+        let end_idx = self.insert_node(end_label);
+
+        self.adj_list[start_idx].push(end_idx);
+        self.adj_list[end_idx].push(start_idx);
+    }
+
+    fn insert_node(&mut self, label: &str) -> usize {
+        if let Some(idx) = self.label_map.get(label) {
+            return *idx;
+        }
+        let idx = self.adj_list.len();
+
+        self.label_map.insert(label.to_string(), idx);
+        self.vertex_map.insert(idx, label.to_string());
+
+        self.adj_list.push(vec![]);
+
+        self.is_small.push(!is_big(label));
+
+        idx
+    }
+
+    fn get_neighbours(&self, vertex: usize) -> &Vec<usize> {
+        &self.adj_list[vertex]
+    }
+
+    fn is_start(&self, vertex: usize) -> bool {
+        "start" == self.vertex_map.get(&vertex).unwrap()
+    }
+
+    fn is_end(&self, vertex: usize) -> bool {
+        "end" == self.vertex_map.get(&vertex).unwrap()
+    }
+
+    fn is_small(&self, vertex: usize) -> bool {
+        self.is_small[vertex]
+    }
+
+    /// Counts the paths from `start` to the end cave.
+    pub fn dfs(&self, start: usize, can_revisit: bool) -> u32 {
+        fn helper(g: &Graph, vertex: usize, marked: &mut Vec<bool>, can_revisit: bool, can_unmark: bool) -> u32 {
+            if g.is_end(vertex) {
+                return 1;
+            }
+
+            if g.is_small(vertex) {
+                marked[vertex] = true;
+            }
+
+            let mut result = 0;
+
+            for nbr in g.get_neighbours(vertex) {
+                if marked[*nbr] && can_revisit && !g.is_start(*nbr) {
+                    result += helper(g, *nbr, marked, false, false);
+
+                }
+                if !marked[*nbr] {
+                    result += helper(g, *nbr, marked, can_revisit, true);
+                }
+            }
+
+            if can_unmark {
+                marked[vertex] = false;
+            }
+
+            result
+        }
+
+        helper(self, start, &mut vec![false; self.adj_list.len()], can_revisit, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_1: &str = include_str!("../test_input_1.txt");
+
+    #[test]
+    fn test_part_1() {
+        let graph_1 = parse(TEST_INPUT_1.as_bytes()).unwrap();
+        let can_revisit = false;
+        let res = solve(can_revisit, &graph_1);
+        assert_eq!(10, res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let graph = parse(TEST_INPUT_1.as_bytes()).unwrap();
+        let can_revisit = true;
+        let res = solve(can_revisit, &graph);
+        assert_eq!(36, res);
+    }
+
+    #[test]
+    fn test_big_caves_joined() {
+        let err = parse("start-A\nA-B\nB-end\n".as_bytes()).unwrap_err();
+        assert_eq!("2:1: expected a passage to at least one small cave, found \"A-B\"", err.to_string());
+    }
+}
@@ -0,0 +1,70 @@
+use aoc_2021_common::generate::rng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use std::collections::HashSet;
+
+/// Number of dots in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 800;
+
+/// Size of the paper once every fold is made, as in the real puzzle input.
+const FOLDED_WIDTH: usize = 40;
+const FOLDED_HEIGHT: usize = 6;
+
+/// Up to `size` dots followed by the folds along each axis. Each fold is exactly halfway across the
+/// paper and no dot lies on a fold, as in the real puzzle input.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+
+    let mut axes = [vec!['x'; rng.gen_range(3..=6)], vec!['y'; rng.gen_range(3..=6)]].concat();
+    axes.shuffle(&mut rng);
+
+    // work out where the folds lie by unfolding the paper, starting from the last fold.
+    let (mut width, mut height) = (FOLDED_WIDTH, FOLDED_HEIGHT);
+    let mut folds = vec![];
+    for axis in axes.iter().rev() {
+        let n = if *axis == 'x' { &mut width } else { &mut height };
+        folds.push((*axis, *n));
+        *n = 2 * *n + 1;
+    }
+    folds.reverse();
+
+    let mut dots = vec![];
+    let mut seen = HashSet::new();
+    for _ in 0..size {
+        let (mut x, mut y) = (rng.gen_range(0..FOLDED_WIDTH), rng.gen_range(0..FOLDED_HEIGHT));
+
+        // each fold maps both halves onto the same side, so the dot may have come from either.
+        for (axis, n) in folds.iter().rev() {
+            if rng.gen_bool(0.5) {
+                if *axis == 'x' { x = 2 * n - x } else { y = 2 * n - y }
+            }
+        }
+
+        if seen.insert((x, y)) {
+            dots.push(format!("{},{}\n", x, y));
+        }
+    }
+
+    let folds = folds.iter().map(|(axis, n)| format!("fold along {}={}\n", axis, n));
+    format!("{}\n{}", dots.concat(), folds.collect::<String>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day13;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day13::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day13::part_1(&input).unwrap();
+            Day13::part_2(&input).unwrap();
+        }
+    }
+}
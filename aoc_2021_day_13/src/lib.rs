@@ -0,0 +1,190 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines, unexpected_end};
+use aoc_2021_common::{Bounds2, ParseError, Point2, Solution, SolveError};
+
+use std::collections::HashSet;
+use std::io::BufRead;
+
+/// Day 13: Transparent Origami.
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = (HashSet<Point>, Vec<Fold>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        let (points, folds) = input;
+        solve_part_1(points.clone(), folds)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        let (points, folds) = input;
+        solve_part_2(points.clone(), folds)
+    }
+}
+
+pub type Point = Point2<usize>;
+
+/// The most cells the folded paper may have for part two to draw it.
+const MAX_SHOWN: usize = 1_000_000;
+
+/// A fold along the horizontal line y = n, or the vertical line x = n.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Fold {
+    Horizontal(usize),
+    Vertical(usize),
+}
+
+impl Fold {
+    /// Where the point ends up after folding, or `None` if it is folded past the edge of the paper,
+    /// as the line is less than halfway across.
+    pub fn fold(&self, mut point: Point) -> Option<Point> {
+        match self {
+            Self::Horizontal(row) => {
+                if point.y < *row { return Some(point); }
+                point.y = row.checked_mul(2)?.checked_sub(point.y)?;
+            },
+            Self::Vertical(col) => {
+                if point.x < *col { return Some(point); }
+                point.x = col.checked_mul(2)?.checked_sub(point.x)?;
+            },
+        }
+
+        Some(point)
+    }
+
+    /// Folds every point, failing if one is folded past the edge of the paper.
+    fn fold_all(&self, points: HashSet<Point>) -> Result<HashSet<Point>, SolveError> {
+        points.into_iter()
+            .map(|p| {
+                self.fold(p).ok_or_else(|| {
+                    SolveError::new(format!("the dot at {},{} is folded off the paper", p.x, p.y))
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns the dots and the fold instructions, which follow the dots after a blank line.
+pub fn parse(input: impl BufRead) -> Result<(HashSet<Point>, Vec<Fold>), ParseError> {
+    parse_lines(input, |lines| {
+        let mut numbered_lines = numbered(lines);
+
+        let mut points = HashSet::new();
+
+        for line in numbered_lines.by_ref() {
+            if line.text.is_empty() {
+                break;
+            }
+
+            let (x, y) = line.split_once(",")?;
+            points.insert(Point::new(
+                line.parse(x, "an x coordinate")?,
+                line.parse(y, "a y coordinate")?,
+            ));
+        }
+
+        let mut folds = vec![];
+
+        for line in numbered_lines {
+            let (axis, n) = line.split_once("=")?;
+
+            let axis = axis.strip_prefix("fold along ")
+                .ok_or_else(|| line.error(axis, "\"fold along\""))?;
+            let n = line.parse(n, "a line to fold along")?;
+
+            let fold = match axis {
+                "x" => Fold::Vertical(n),
+                "y" => Fold::Horizontal(n),
+                _ => return Err(line.error(axis, "\"x\" or \"y\"")),
+            };
+
+            folds.push(fold);
+        }
+
+        if folds.is_empty() {
+            return Err(unexpected_end(lines, "a fold instruction"));
+        }
+
+        Ok((points, folds))
+    })
+}
+
+/// Number of dots visible after the first fold.
+pub fn solve_part_1(points: HashSet<Point>, folds: &[Fold]) -> Result<usize, SolveError> {
+    let fold = folds.first().ok_or_else(|| SolveError::new("there are no fold instructions"))?;
+
+    Ok(fold.fold_all(points)?.len())
+}
+
+/// Returns the folded paper rendered as rows of '#' and '.'.
+pub fn solve_part_2(mut points: HashSet<Point>, folds: &[Fold]) -> Result<String, SolveError> {
+    for fold in folds {
+        points = fold.fold_all(points)?;
+    }
+
+    let max = match Bounds2::of(points.iter().copied()) {
+        Some(bounds) => bounds.max,
+        None => return Ok(String::new()),
+    };
+
+    if max.x >= MAX_SHOWN || max.y >= MAX_SHOWN || (max.x + 1) * (max.y + 1) > MAX_SHOWN {
+        return Err(SolveError::new("the folded paper is too large to show"));
+    }
+
+    let mut visualisation = vec![vec!["."; max.x + 1]; max.y + 1];
+    for point in points.iter() {
+        visualisation[point.y][point.x] = "#";
+    }
+
+    Ok(visualisation.iter()
+        .map(|row| row.concat())
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        let (points, folds) = parse(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve_part_1(points, &folds).unwrap();
+        assert_eq!(17, res);
+    }
+
+    #[test]
+    fn test_unfoldable() {
+        let (points, folds) = parse("0,0\n0,5\n\nfold along y=2\n".as_bytes()).unwrap();
+        assert_eq!(
+            Err(SolveError::new("the dot at 0,5 is folded off the paper")),
+            solve_part_1(points, &folds)
+        );
+
+        // the first fold is far less than halfway across, so most dots go off the paper.
+        let (points, folds) = parse(
+            "389,22\n514,33\n534,7\n185,10\n634,51\n\n\
+             fold along x=27\nfold along x=163\nfold along y=27\nfold along x=81\n"
+                .as_bytes(),
+        ).unwrap();
+        let err = solve_part_2(points, &folds).unwrap_err();
+        assert!(err.to_string().ends_with("is folded off the paper"), "{}", err);
+
+        let (points, folds) = parse("0,0\n5000,5000\n\nfold along y=5000\n".as_bytes()).unwrap();
+        assert_eq!(
+            Err(SolveError::new("the folded paper is too large to show")),
+            solve_part_2(points, &folds)
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use aoc_2021_common::generate::rng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Length of the template in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 20;
+
+/// Number of distinct elements, as in the real puzzle input.
+const ELEMENTS: usize = 10;
+
+/// A template of `size` elements, and an insertion rule for every pair of elements.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+
+    let mut elements = ('A'..='Z').collect::<Vec<char>>();
+    elements.shuffle(&mut rng);
+    elements.truncate(ELEMENTS);
+
+    let template = (0..size.max(2))
+        .map(|_| *elements.choose(&mut rng).unwrap())
+        .collect::<String>();
+
+    let mut rules = vec![];
+    for a in elements.iter() {
+        for b in elements.iter() {
+            rules.push(format!("{}{} -> {}\n", a, b, elements[rng.gen_range(0..ELEMENTS)]));
+        }
+    }
+    rules.shuffle(&mut rng);
+
+    format!("{}\n\n{}", template, rules.concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day14;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day14::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day14::part_1(&input).unwrap();
+            Day14::part_2(&input).unwrap();
+        }
+    }
+}
@@ -0,0 +1,231 @@
+pub mod generate;
+pub mod reference;
+
+use aoc_2021_common::parse::{numbered, parse_lines, unexpected_end, Line};
+use aoc_2021_common::{Param, Params, ParseError, Solution, SolveError};
+
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::ops::RangeBounds;
+
+/// Day 14: Extended Polymerization.
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "steps_1",
+            default: 10,
+            min: 0,
+            max: 10_000,
+            about: "insertion steps for part one",
+        },
+        Param {
+            name: "steps_2",
+            default: 40,
+            min: 0,
+            max: 10_000,
+            about: "insertion steps for part two",
+        },
+    ];
+
+    type Input = Polymer;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Self::part_1_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Self::part_2_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part_1_with(input: &Self::Input, params: &Params) -> Result<Self::Answer1, SolveError> {
+        solve(input, params.try_get("steps_1")?).ok_or_else(too_many_elements)
+    }
+
+    fn part_2_with(input: &Self::Input, params: &Params) -> Result<Self::Answer2, SolveError> {
+        solve(input, params.try_get("steps_2")?).ok_or_else(too_many_elements)
+    }
+}
+
+fn too_many_elements() -> SolveError {
+    SolveError::new("the polymer has too many elements to count")
+}
+
+/// Maps a pair's index to the indices of the two pairs it becomes after an insertion step.
+pub type DescendentsMap = HashMap<usize, (usize, usize)>;
+
+/// The polymer template and pair insertion rules, indexed by pair.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polymer {
+    pub template: String,
+    /// Every pair with an insertion rule.
+    pub pairs: Vec<String>,
+    /// How often each pair occurs in the template.
+    pub initial_pair_freq: Vec<u64>,
+    pub descendents_map: DescendentsMap,
+}
+
+/// Returns mappings from a pair to the index of the character it inserts and to the two descendent
+/// pairs it becomes.
+/// e.g. "CH" -> B = 1
+///      "CH" -> ("CB", BH")
+pub fn parse(input: impl BufRead) -> Result<Polymer, ParseError> {
+    parse_lines(input, |lines| {
+        let mut lines_iter = numbered(lines);
+
+        let template_line = lines_iter.next()
+            .ok_or_else(|| unexpected_end(lines, "a polymer template"))?;
+        let template = elements(&template_line, template_line.text, 2.., "a polymer template")?;
+
+        match lines_iter.next() {
+            Some(line) if line.text.is_empty() => (),
+            Some(line) => return Err(line.error(line.text, "a blank line")),
+            None => return Err(unexpected_end(lines, "a blank line")),
+        }
+
+        let mut pair_index_map = HashMap::new();
+
+        let mut pairs = vec![];
+        let mut descendents = vec![];
+
+        for (i, line) in lines_iter.enumerate() {
+            let (pair, insert) = line.split_once(" -> ")?;
+
+            let pair_chars = elements(&line, pair, 2..=2, "a pair of elements")?;
+            let insert_char = elements(&line, insert, 1..=1, "an element")?[0];
+
+            if pair_index_map.insert(pair.to_string(), i).is_some() {
+                return Err(line.error(pair, "a pair without an earlier rule"));
+            }
+
+            let left_descendent = [pair_chars[0], insert_char].iter().collect::<String>();
+            let right_descendent = [insert_char, pair_chars[1]].iter().collect::<String>();
+
+            pairs.push(pair.to_string());
+
+            descendents.push((line, left_descendent, right_descendent));
+        }
+
+        let mut descendents_map: DescendentsMap = HashMap::new();
+
+        for (i, (line, left_str, right_str)) in descendents.iter().enumerate() {
+            let index_of = |pair: &str| {
+                pair_index_map.get(pair)
+                    .copied()
+                    .ok_or_else(|| line.error_at(1, format!("a rule for the pair {:?}", pair)))
+            };
+            descendents_map.insert(i, (index_of(left_str)?, index_of(right_str)?));
+        }
+
+        let mut initial_freq = vec![0; pair_index_map.len()];
+
+        for (i, pair) in template.windows(2).enumerate() {
+            let pair_str = pair.iter().collect::<String>();
+            let pair_idx = pair_index_map.get(&pair_str)
+                .ok_or_else(|| {
+                    template_line.error_at(i + 1, format!("a rule for the pair {:?}", pair_str))
+                })?;
+            initial_freq[*pair_idx] += 1;
+        }
+
+        let template = template_line.text.to_string();
+
+        Ok(Polymer { template, pairs, initial_pair_freq: initial_freq, descendents_map })
+    })
+}
+
+/// Parses `part` of `line` as a run of elements (uppercase letters) of a length within `len`.
+fn elements<R>(line: &Line, part: &str, len: R, expected: &str) -> Result<Vec<char>, ParseError>
+where
+    R: RangeBounds<usize>,
+{
+    let chars = part.chars().collect::<Vec<char>>();
+
+    if !len.contains(&chars.len()) || !chars.iter().all(|c| c.is_ascii_uppercase()) {
+        return Err(line.error(part, expected));
+    }
+
+    Ok(chars)
+}
+
+/// Difference between the most and least common elements after the given number of insertion
+/// steps, or `None` if there are too many to count.
+pub fn solve(polymer: &Polymer, steps: u32) -> Option<u64> {
+    let Polymer { template, pairs, initial_pair_freq, descendents_map } = polymer;
+
+    let mut pair_freq = initial_pair_freq.clone();
+
+    for _ in 0..steps {
+        let next = vec![0u64; pair_freq.len()];
+        pair_freq = pair_freq.iter().enumerate().try_fold(next, |mut acc, (i, freq)| {
+            if *freq == 0 { return Some(acc); }
+            let (left, right) = descendents_map.get(&i).unwrap();
+            acc[*left] = acc[*left].checked_add(*freq)?;
+            acc[*right] = acc[*right].checked_add(*freq)?;
+            Some(acc)
+        })?;
+    }
+
+    let mut char_freq = pair_freq.iter()
+        .enumerate()
+        .try_fold(vec![0u64; 26], |mut acc, (i, freq)| {
+            let chars = pairs[i].chars().collect::<Vec<char>>();
+            for c in [chars[0], chars[1]] {
+                let count = &mut acc[c as usize - 'A' as usize];
+                *count = count.checked_add(*freq)?;
+            }
+            Some(acc)
+        })?;
+
+    // every element is in two pairs except the first and last, which never change. Count them
+    // twice as well before halving, as they may be the same element.
+    let template_chars = template.chars().collect::<Vec<char>>();
+    char_freq[template_chars[0] as usize - 'A' as usize] += 1;
+    char_freq[*template_chars.last().unwrap() as usize - 'A' as usize] += 1;
+
+    for freq in char_freq.iter_mut() {
+        *freq /= 2;
+    }
+
+    Some(*char_freq.iter().max().unwrap() - *char_freq.iter().filter(|&&n| n != 0).min().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        let polymer = parse(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(&polymer, 10);
+        assert_eq!(Some(1588), res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let polymer = parse(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve(&polymer, 40);
+        assert_eq!(Some(2188189693529), res);
+    }
+
+    #[test]
+    fn test_params() {
+        let polymer = parse(TEST_INPUT.as_bytes()).unwrap();
+        let params = Params::parse(Day14::PARAMS, &["steps_2=100".to_string()]).unwrap();
+
+        assert!(Day14::part_2_with(&polymer, &params).is_err());
+        assert!(Params::parse(Day14::PARAMS, &["steps_1=4294967296".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+//! A slow but obviously correct solver, to check the fast one against.
+
+use crate::Polymer;
+
+use std::collections::HashMap;
+
+/// Difference between the most and least common elements after building the whole polymer, one
+/// insertion step at a time. Its length doubles with each step, so only use this for a few steps.
+pub fn solve(polymer: &Polymer, steps: u32) -> u64 {
+    let rules = polymer.pairs.iter()
+        .enumerate()
+        .map(|(i, pair)| {
+            // the left descendent of "AB" is "AC", where C is the inserted element.
+            let (left, _) = polymer.descendents_map[&i];
+            let insert = polymer.pairs[left].chars().nth(1).unwrap();
+            let pair = pair.chars().collect::<Vec<char>>();
+            ((pair[0], pair[1]), insert)
+        })
+        .collect::<HashMap<(char, char), char>>();
+
+    let mut elements = polymer.template.chars().collect::<Vec<char>>();
+
+    for _ in 0..steps {
+        let mut next = vec![elements[0]];
+        for pair in elements.windows(2) {
+            next.push(rules[&(pair[0], pair[1])]);
+            next.push(pair[1]);
+        }
+        elements = next;
+    }
+
+    let mut counts = HashMap::new();
+    for e in elements {
+        *counts.entry(e).or_insert(0) += 1;
+    }
+
+    counts.values().max().unwrap() - counts.values().min().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_example() {
+        let polymer = crate::parse(TEST_INPUT.as_bytes()).unwrap();
+        assert_eq!(1588, solve(&polymer, 10));
+
+        // with no steps, just the template is counted.
+        assert_eq!(1, solve(&polymer, 0));
+        assert_eq!(Some(1), crate::solve(&polymer, 0));
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_solver(seed in any::<u64>(), size in 2..10usize, steps in 0..10u32) {
+            let polymer = crate::parse(generate(seed, size).as_bytes()).unwrap();
+
+            prop_assert_eq!(Some(solve(&polymer, steps)), crate::solve(&polymer, steps));
+        }
+    }
+}
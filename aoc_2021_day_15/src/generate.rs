@@ -0,0 +1,31 @@
+use aoc_2021_common::generate::{char_grid, rng};
+use rand::Rng;
+
+/// Width and height of the real puzzle input.
+pub const DEFAULT_SIZE: usize = 100;
+
+/// A `size` by `size` grid of risk levels from 1 to 9.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+
+    char_grid(size.max(1), size.max(1), || rng.gen_range('1'..='9'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day15;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day15::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day15::part_1(&input).unwrap();
+            Day15::part_2(&input).unwrap();
+        }
+    }
+}
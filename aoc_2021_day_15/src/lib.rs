@@ -0,0 +1,147 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines};
+use aoc_2021_common::{Grid, Param, Params, ParseError, Solution, SolveError};
+use tracing::trace;
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+/// Day 15: Chiton.
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "factor",
+        default: 5,
+        min: 1,
+        max: 50,
+        about: "times the map is tiled in each direction for part two",
+    }];
+
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(dijkstra(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Self::part_2_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part_2_with(input: &Self::Input, params: &Params) -> Result<Self::Answer2, SolveError> {
+        Ok(dijkstra(&expand_grid(input, params.try_get("factor")?)))
+    }
+}
+
+/// Parses the risk level of each position, one row of digits from 1 to 9 per line.
+pub fn parse(input: impl BufRead) -> Result<Grid<u8>, ParseError> {
+    parse_lines(input, |lines| {
+        Grid::parse_chars(numbered(lines), "a risk level from 1 to 9", |c| {
+            c.to_digit(10).filter(|d| *d > 0).map(|d| d as u8)
+        })
+    })
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct State {
+    risk: usize,
+    x: usize,
+    y: usize,
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.risk.cmp(&self.risk)
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Tiles the grid `factor` times in each direction, increasing the risk levels of each tile by its
+/// distance from the original, wrapping around from 9 back to 1.
+pub fn expand_grid(grid: &Grid<u8>, factor: usize) -> Grid<u8> {
+    let (rows, cols) = (grid.rows(), grid.cols());
+
+    Grid::from_fn(rows * factor, cols * factor, |x, y| {
+        let distance = x / rows + y / cols;
+
+        ((grid[(x % rows, y % cols)] as usize + distance + 8) % 9 + 1) as u8
+    })
+}
+
+/// Lowest total risk of any path from the top left to the bottom right.
+pub fn dijkstra(grid: &Grid<u8>) -> usize {
+    let mut fringe: BinaryHeap<State> = BinaryHeap::new();
+    let mut risk_to = Grid::new(grid.rows(), grid.cols(), usize::MAX);
+    let destination = (grid.rows() - 1, grid.cols() - 1);
+
+    // insert start
+    fringe.push(State { risk: 0, x: 0, y: 0 });
+
+    while let Some(State { risk, x, y }) = fringe.pop() {
+        trace!(risk, x, y, "pop");
+
+        // check if reached destination.
+        if (x, y) == destination {
+            return risk;
+        }
+
+        // check if a better path has already been found.
+        if risk > risk_to[(x, y)] {
+            continue;
+        }
+
+        // add neighbours to fringe.
+        for (nbr_x, nbr_y) in grid.neighbours_4(x, y) {
+            let nbr_risk = risk + grid[(nbr_x, nbr_y)] as usize;
+
+            if nbr_risk < risk_to[(nbr_x, nbr_y)] {
+                fringe.push(State { risk: nbr_risk, x: nbr_x, y: nbr_y });
+                risk_to[(nbr_x, nbr_y)] = nbr_risk;
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        let grid = parse(TEST_INPUT.as_bytes()).unwrap();
+        let res = dijkstra(&grid);
+        assert_eq!(40, res);
+
+        let err = parse("19\n90\n".as_bytes()).unwrap_err();
+        assert_eq!("2:2: expected a risk level from 1 to 9, found \"0\"", err.to_string());
+
+        let err = parse("37947\n21497\n96958\n12043\n47163\n".as_bytes()).unwrap_err();
+        assert_eq!("4:3: expected a risk level from 1 to 9, found \"0\"", err.to_string());
+    }
+
+    #[test]
+    fn test_part_2() {
+        let grid = expand_grid(&parse(TEST_INPUT.as_bytes()).unwrap(), 5);
+        let res = dijkstra(&grid);
+        assert_eq!(315, res);
+    }
+}
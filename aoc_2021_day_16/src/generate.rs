@@ -0,0 +1,137 @@
+use aoc_2021_common::generate::rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Roughly the number of packets in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 250;
+
+/// Most sub-packets given to a single operator.
+const MAX_OPERANDS: usize = 8;
+
+/// A transmission of about `size` packets, as a single hexadecimal line. Products only ever take
+/// small literal operands, so evaluating the transmission never overflows.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let mut bits = vec![];
+
+    packet(&mut rng, size.max(1), &mut bits);
+
+    // the transmission is padded out to a whole number of bytes.
+    while bits.len() % 8 != 0 {
+        bits.push(false);
+    }
+
+    let mut result = bits.chunks(4)
+        .map(|nibble| {
+            let digit = nibble.iter().fold(0, |acc, bit| (acc << 1) + *bit as u32);
+            std::char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect::<String>();
+    result.push('\n');
+
+    result
+}
+
+/// Appends a packet made up of about `budget` packets, including itself.
+fn packet(rng: &mut StdRng, budget: usize, bits: &mut Vec<bool>) {
+    push_bits(bits, rng.gen_range(0..8), 3);
+
+    let type_id = match budget {
+        1 => 4,
+        2 => *[0, 1, 2, 3].choose(rng).unwrap(),
+        _ => *[0, 1, 2, 3, 5, 6, 7].choose(rng).unwrap(),
+    };
+    push_bits(bits, type_id, 3);
+
+    if type_id == 4 {
+        literal(bits, rng.gen_range(0..4096));
+        return;
+    }
+
+    let mut sub_pkts = vec![];
+    match type_id {
+        1 => {
+            for _ in 0..rng.gen_range(1..=(budget - 1).min(3)) {
+                let mut sub_pkt = vec![];
+                push_bits(&mut sub_pkt, rng.gen_range(0..8), 3);
+                push_bits(&mut sub_pkt, 4, 3);
+                literal(&mut sub_pkt, rng.gen_range(0..256));
+                sub_pkts.push(sub_pkt);
+            }
+        },
+        _ => {
+            let operands = if type_id >= 5 {
+                2
+            } else {
+                rng.gen_range(1..=(budget - 1).min(MAX_OPERANDS))
+            };
+
+            for share in split(rng, budget - 1, operands) {
+                let mut sub_pkt = vec![];
+                packet(rng, share, &mut sub_pkt);
+                sub_pkts.push(sub_pkt);
+            }
+        },
+    }
+
+    let total_len = sub_pkts.iter().map(|p| p.len()).sum::<usize>();
+    if total_len < 1 << 15 && rng.gen_bool(0.5) {
+        bits.push(false);
+        push_bits(bits, total_len, 15);
+    } else {
+        bits.push(true);
+        push_bits(bits, sub_pkts.len(), 11);
+    }
+
+    for sub_pkt in sub_pkts {
+        bits.extend(sub_pkt);
+    }
+}
+
+/// Appends the literal value in groups of 4 bits, each prefixed by whether another follows.
+fn literal(bits: &mut Vec<bool>, value: usize) {
+    let groups = (0..).take_while(|i| *i == 0 || value >> (4 * i) != 0).count();
+
+    for i in (0..groups).rev() {
+        bits.push(i > 0);
+        push_bits(bits, (value >> (4 * i)) & 0xf, 4);
+    }
+}
+
+/// Appends the lowest `n` bits of `value`, most significant first.
+fn push_bits(bits: &mut Vec<bool>, value: usize, n: usize) {
+    for i in (0..n).rev() {
+        bits.push(value >> i & 1 == 1);
+    }
+}
+
+/// Splits `total` into `parts` random shares of at least 1 each.
+fn split(rng: &mut StdRng, total: usize, parts: usize) -> Vec<usize> {
+    let mut shares = vec![1; parts];
+
+    for _ in parts..total {
+        shares[rng.gen_range(0..parts)] += 1;
+    }
+
+    shares
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day16;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day16::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day16::part_1(&input).unwrap();
+            Day16::part_2(&input).unwrap();
+        }
+    }
+}
@@ -0,0 +1,369 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{parse_lines, unexpected_end};
+use aoc_2021_common::{ParseError, Solution, SolveError};
+
+use std::io::BufRead;
+
+/// Day 16: Packet Decoder.
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Packet;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        let mut data = parse(input)?;
+
+        data.get_packet()
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(input.sum_versions())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(input.evaluate())
+    }
+}
+
+/// Reads the hexadecimal transmission from the first line.
+pub fn parse(input: impl BufRead) -> Result<Data, ParseError> {
+    parse_lines(input, |lines| {
+        let input = lines.first().ok_or_else(|| unexpected_end(lines, "a transmission"))?;
+
+        Data::new(input)
+    })
+}
+
+/// A BITS transmission, read from the front one bit at a time.
+pub struct Data {
+    bit_vec: Vec<u8>,
+    pos: usize,
+}
+
+impl Data {
+    /// Decodes a hexadecimal transmission, given on a single line.
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let digits = input
+            .trim_end()
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                c.to_digit(16).ok_or_else(|| {
+                    ParseError::expected(1, i + 1, "a hexadecimal digit").found(c.to_string())
+                })
+            })
+            .collect::<Result<Vec<u32>, ParseError>>()?;
+
+        if digits.is_empty() || digits.len() % 2 != 0 {
+            return Err(ParseError::expected(1, digits.len() + 1, "a whole number of bytes"));
+        }
+
+        let bit_vec = digits
+            .chunks(2)
+            .map(|x| ((x[0] << 4) + x[1]) as u8)
+            .collect::<Vec<u8>>();
+
+        Ok(Self { bit_vec, pos: 0 })
+    }
+
+    fn parse_n_bits(&mut self, n: usize) -> Result<usize, ParseError> {
+        if self.pos + n > 8 * self.bit_vec.len() {
+            return Err(self.error("the rest of the packet"));
+        }
+
+        let mut result = 0;
+
+        for i in (0..n).rev() {
+            result += (self.next_bit() as usize) << i;
+        }
+
+        Ok(result)
+    }
+
+    /// An error at the hexadecimal digit holding the current bit.
+    fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::expected(1, self.pos / 4 + 1, expected)
+    }
+
+    fn next_bit(&mut self) -> u8 {
+        let chunk = self.pos / 8;
+        let idx = self.pos % 8;
+
+        let result = if (self.bit_vec[chunk] & (128 >> idx)) == 0 {
+            0
+        } else {
+            1
+        };
+
+        self.pos += 1;
+
+        result
+    }
+
+    /// Reads the next packet, along with all of its sub-packets.
+    pub fn get_packet(&mut self) -> Result<Packet, ParseError> {
+        let header = Header {
+            ver: self.parse_n_bits(3)?,
+            type_id: self.parse_n_bits(3)?,
+        };
+
+        let mut sub_pkts: Vec<Packet> = vec![];
+
+        let body = if header.type_id == 4 {
+            // contains a literal value.
+            let mut literal = 0;
+
+            while self.parse_n_bits(1)? == 1 {
+                literal <<= 4;
+                literal += self.parse_n_bits(4)?;
+            }
+            literal <<= 4;
+            literal += self.parse_n_bits(4)?;
+
+            Body::Literal(literal)
+        } else {
+            // an operation on sub-packets.
+            let len_type_id = self.parse_n_bits(1)?;
+
+            // recursively parse sub-packets.
+            match len_type_id {
+                0 => {
+                    let total_subpkt_len = self.parse_n_bits(15)?;
+                    let start_pos = self.pos;
+
+                    while (self.pos - start_pos) < total_subpkt_len {
+                        sub_pkts.push(self.get_packet()?);
+                    }
+
+                    if self.pos - start_pos != total_subpkt_len {
+                        return Err(self.error(
+                            format!("sub-packets totalling {} bits", total_subpkt_len)
+                        ));
+                    }
+                },
+                1 => {
+                    let num_subpkts = self.parse_n_bits(11)?;
+
+                    for _ in 0..num_subpkts {
+                        sub_pkts.push(self.get_packet()?);
+                    }
+                },
+                _ => unreachable!(),
+            }
+
+            let operands_ok = match header.type_id {
+                2 | 3 => !sub_pkts.is_empty(),
+                5..=7 => sub_pkts.len() == 2,
+                _ => true,
+            };
+            if !operands_ok {
+                return Err(self.error(format!("valid operands for packet type {}", header.type_id)));
+            }
+
+            Body::Operator
+        };
+
+        Ok(Packet { header, body, sub_pkts })
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Packet {
+    pub header: Header,
+    pub body: Body,
+    pub sub_pkts: Vec<Packet>,
+}
+
+impl Packet {
+    /// Sum of the version numbers of this packet and all of its sub-packets.
+    pub fn sum_versions(&self) -> usize {
+        let mut result = self.header.ver;
+
+        for subpkt in self.sub_pkts.iter() {
+            result += subpkt.sum_versions();
+        }
+
+        result
+    }
+
+    /// The value of the expression the packet represents.
+    pub fn evaluate(&self) -> usize {
+        match self.body {
+            Body::Literal(val) => val,
+            Body::Operator => self.evaluate_operation(),
+        }
+    }
+
+    fn evaluate_operation(&self) -> usize {
+        match self.header.type_id {
+            0 => self.sub_pkts.iter().map(|p| p.evaluate()).sum(),
+            1 => self.sub_pkts.iter().map(|p| p.evaluate()).product(),
+            2 => self.sub_pkts.iter().map(|p| p.evaluate()).min().unwrap(),
+            3 => self.sub_pkts.iter().map(|p| p.evaluate()).max().unwrap(),
+            5 => {
+                if self.sub_pkts[0].evaluate() > self.sub_pkts[1].evaluate() {
+                    1
+                } else {
+                    0
+                }
+            },
+            6 => {
+                if self.sub_pkts[0].evaluate() < self.sub_pkts[1].evaluate() {
+                    1
+                } else {
+                    0
+                }
+            },
+            7 => {
+                if self.sub_pkts[0].evaluate() == self.sub_pkts[1].evaluate() {
+                    1
+                } else {
+                    0
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Header {
+    pub ver: usize,
+    pub type_id: usize,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Body {
+    Literal(usize),
+    Operator,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_0: &str = "EE00D40C823060";
+    const INPUT_1: &str = "38006F45291200";
+    const INPUT_2: &str = "8A004A801A8002F478";
+    const INPUT_3: &str = "620080001611562C8802118E34";
+    const INPUT_4: &str = "C0015000016115A2E0802F182340";
+    const INPUT_5: &str = "A0016C880162017C3686B18A3D4780";
+
+    const PT2_INPUT_0: &str = "C200B40A82";
+    const PT2_INPUT_1: &str = "04005AC33890";
+    const PT2_INPUT_2: &str = "880086C3E88112";
+    const PT2_INPUT_3: &str = "CE00C43D881120";
+    const PT2_INPUT_4: &str = "D8005AC2A8F0";
+    const PT2_INPUT_5: &str = "F600BC2D8F";
+    const PT2_INPUT_6: &str = "9C005AC2F8F0";
+    const PT2_INPUT_7: &str = "9C0141080250320F1802104A08";
+
+    #[test]
+    fn test_data_generation() {
+        let mut data = Data::new(INPUT_2).unwrap();
+        let expected = vec![
+            0b10001010,
+            0b00000000,
+            0b01001010,
+            0b10000000,
+            0b00011010,
+            0b10000000,
+            0b00000010,
+            0b11110100,
+            0b01111000,
+        ];
+
+        for (expected, chunk) in data.bit_vec.iter().zip(expected.iter()) {
+            print!("{:0>8b} ", chunk);
+            assert_eq!(expected, chunk);
+        }
+
+        assert_eq!(4, data.parse_n_bits(3).unwrap());
+        assert_eq!(2, data.parse_n_bits(3).unwrap());
+        assert_eq!(1, data.parse_n_bits(1).unwrap());
+        assert_eq!(1, data.parse_n_bits(11).unwrap());
+        assert_eq!(1, data.parse_n_bits(3).unwrap());
+        assert_eq!(2, data.parse_n_bits(3).unwrap());
+        assert_eq!(1, data.parse_n_bits(1).unwrap());
+        assert_eq!(1, data.parse_n_bits(11).unwrap());
+        assert_eq!(5, data.parse_n_bits(3).unwrap());
+        assert_eq!(2, data.parse_n_bits(3).unwrap());
+        assert_eq!(0, data.parse_n_bits(1).unwrap());
+        assert_eq!(11, data.parse_n_bits(15).unwrap());
+        assert_eq!(6, data.parse_n_bits(3).unwrap());
+        assert_eq!(4, data.parse_n_bits(3).unwrap());
+        assert_eq!(15, data.parse_n_bits(5).unwrap());
+    }
+
+    #[test]
+    fn test_part_1() {
+        let mut data_0 = Data::new(INPUT_0).unwrap();
+        let mut data_1 = Data::new(INPUT_1).unwrap();
+        let mut data_2 = Data::new(INPUT_2).unwrap();
+        let mut data_3 = Data::new(INPUT_3).unwrap();
+        let mut data_4 = Data::new(INPUT_4).unwrap();
+        let mut data_5 = Data::new(INPUT_5).unwrap();
+
+        let pkt_0 = data_0.get_packet().unwrap();
+        let pkt_1 = data_1.get_packet().unwrap();
+        let pkt_2 = data_2.get_packet().unwrap();
+        let pkt_3 = data_3.get_packet().unwrap();
+        let pkt_4 = data_4.get_packet().unwrap();
+        let pkt_5 = data_5.get_packet().unwrap();
+
+        assert_eq!(14, pkt_0.sum_versions());
+        assert_eq!(9, pkt_1.sum_versions());
+        assert_eq!(16, pkt_2.sum_versions());
+        assert_eq!(12, pkt_3.sum_versions());
+        assert_eq!(23, pkt_4.sum_versions());
+        assert_eq!(31, pkt_5.sum_versions());
+    }
+
+    #[test]
+    fn test_part_2() {
+        let mut data_0 = Data::new(PT2_INPUT_0).unwrap();
+        let mut data_1 = Data::new(PT2_INPUT_1).unwrap();
+        let mut data_2 = Data::new(PT2_INPUT_2).unwrap();
+        let mut data_3 = Data::new(PT2_INPUT_3).unwrap();
+        let mut data_4 = Data::new(PT2_INPUT_4).unwrap();
+        let mut data_5 = Data::new(PT2_INPUT_5).unwrap();
+        let mut data_6 = Data::new(PT2_INPUT_6).unwrap();
+        let mut data_7 = Data::new(PT2_INPUT_7).unwrap();
+
+        let pkt_0 = data_0.get_packet().unwrap();
+        let pkt_1 = data_1.get_packet().unwrap();
+        let pkt_2 = data_2.get_packet().unwrap();
+        let pkt_3 = data_3.get_packet().unwrap();
+        let pkt_4 = data_4.get_packet().unwrap();
+        let pkt_5 = data_5.get_packet().unwrap();
+        let pkt_6 = data_6.get_packet().unwrap();
+        let pkt_7 = data_7.get_packet().unwrap();
+
+        assert_eq!(3, pkt_0.evaluate());
+        assert_eq!(54, pkt_1.evaluate());
+        assert_eq!(7, pkt_2.evaluate());
+        assert_eq!(9, pkt_3.evaluate());
+        assert_eq!(1, pkt_4.evaluate());
+        assert_eq!(0, pkt_5.evaluate());
+        assert_eq!(0, pkt_6.evaluate());
+        assert_eq!(1, pkt_7.evaluate());
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = Data::new("D2FE2").err().unwrap();
+        assert_eq!("1:6: expected a whole number of bytes", err.to_string());
+
+        // a literal cut short after its first group.
+        let err = Data::new("D2FE").unwrap().get_packet().unwrap_err();
+        assert_eq!("1:5: expected the rest of the packet", err.to_string());
+    }
+}
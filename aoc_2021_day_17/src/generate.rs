@@ -0,0 +1,39 @@
+use aoc_2021_common::generate::rng;
+use rand::Rng;
+
+/// Roughly how far the target area lies from the launch position in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 100;
+
+/// A target area between `size` and `2 * size` ahead and up to `size` below the launch position.
+/// Unlike the real puzzle input, no initial x velocity need leave the probe stopped above the area.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let size = size.max(1) as isize;
+
+    let min_x = rng.gen_range(size..=2 * size);
+    let max_x = min_x + rng.gen_range(0..=size / 2);
+
+    let min_y = -rng.gen_range(size / 2 + 1..=size + 1);
+    let max_y = (min_y + rng.gen_range(0..=size / 2)).min(-1);
+
+    format!("target area: x={}..{}, y={}..{}\n", min_x, max_x, min_y, max_y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day17;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day17::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day17::part_1(&input).unwrap();
+            Day17::part_2(&input).unwrap();
+        }
+    }
+}
@@ -0,0 +1,218 @@
+pub mod generate;
+pub mod reference;
+
+use aoc_2021_common::parse::{numbered, parse_lines, unexpected_end, Line};
+use aoc_2021_common::{ParseError, Solution, SolveError};
+
+use std::io::BufRead;
+
+/// Day 17: Trick Shot.
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = TargetArea;
+    type Answer1 = isize;
+    type Answer2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        highest_y_position(*input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(number_of_accurate_initial_velocities(*input))
+    }
+}
+
+/// ((min x, max x), (min y, max y)).
+pub type TargetArea = ((isize, isize), (isize, isize));
+
+/// The furthest the target area may lie from the launch position along either axis, as every
+/// velocity that could reach it is tried.
+pub const MAX_DISTANCE: isize = 500;
+
+/// Parses the target area, e.g. "target area: x=20..30, y=-10..-5". It must lie ahead of and below
+/// the launch position, within `MAX_DISTANCE` of it.
+pub fn parse(input: impl BufRead) -> Result<TargetArea, ParseError> {
+    parse_lines(input, |lines| {
+        let line = numbered(lines).next().ok_or_else(|| unexpected_end(lines, "a target area"))?;
+
+        let (x, y) = line.split_once(", ")?;
+        let x = x.strip_prefix("target area: ")
+            .ok_or_else(|| line.error(x, "\"target area: \""))?;
+
+        let (min_x, max_x) = parse_range(&line, x, "x")?;
+        let (min_y, max_y) = parse_range(&line, y, "y")?;
+
+        if min_x <= 0 {
+            return Err(line.error(x, "an x range ahead of the launch position"));
+        }
+        if max_y >= 0 {
+            return Err(line.error(y, "a y range below the launch position"));
+        }
+        if max_x > MAX_DISTANCE || min_y < -MAX_DISTANCE {
+            let expected = format!("a target area within {} of the launch position", MAX_DISTANCE);
+            return Err(line.error(line.text, expected));
+        }
+
+        Ok(((min_x, max_x), (min_y, max_y)))
+    })
+}
+
+/// Parses `part` of `line` as an inclusive range along `axis`, e.g. "x=20..30".
+fn parse_range(line: &Line, part: &str, axis: &str) -> Result<(isize, isize), ParseError> {
+    let expected = format!("a range such as \"{}=20..30\"", axis);
+
+    let (min, max) = part.strip_prefix(axis)
+        .and_then(|r| r.strip_prefix('='))
+        .and_then(|r| r.split_once(".."))
+        .ok_or_else(|| line.error(part, &expected))?;
+
+    let min = line.parse(min, "a number")?;
+    let max = line.parse(max, "a number")?;
+
+    if min > max {
+        return Err(line.error(part, &expected));
+    }
+
+    Ok((min, max))
+}
+
+/// Highest y position reached by any trajectory that hits the target area.
+///
+/// Launched upwards at some y velocity, the probe comes back down through y = 0 one faster, so it
+/// overshoots the area on the next step if that is faster than the area is deep. The fastest that
+/// does not lands on the bottom edge of the area, and hits it if some x velocity stops the probe
+/// above the area by then, as one nearly always does. Otherwise slower y velocities are tried in
+/// turn until one is within the area on the same step as some x velocity.
+pub fn highest_y_position(target_area: TargetArea) -> Result<isize, SolveError> {
+    let ((min_x, max_x), (min_y, _)) = target_area;
+
+    let stops_above = (1..=max_x)
+        .map(|x_vel| x_vel * (x_vel + 1) / 2)
+        .take_while(|&x| x <= max_x)
+        .any(|x| x >= min_x);
+
+    let y_vel = if stops_above {
+        -min_y - 1
+    } else {
+        (min_y..-min_y)
+            .rev()
+            .find(|&y_vel| (1..=max_x).any(|x_vel| is_hit(x_vel, y_vel, target_area)))
+            .ok_or_else(|| SolveError::new("no trajectory hits the target area"))?
+    };
+    let y_vel = y_vel.max(0);
+
+    Ok(y_vel * (y_vel + 1) / 2)
+}
+
+/// Number of distinct initial velocities whose trajectories hit the target area.
+pub fn number_of_accurate_initial_velocities(target_area: TargetArea) -> usize {
+    let mut result = 0;
+
+    let ((target_min_x, target_max_x), (target_min_y, _)) = target_area;
+
+    let min_y_vel = target_min_y;
+    let max_y_vel = target_min_y.abs() - 1;
+    let min_x_vel = target_min_x.abs() / target_min_x;
+    let max_x_vel = target_max_x;
+
+    for initial_x_vel in min_x_vel..=max_x_vel {
+        for initial_y_vel in min_y_vel..=max_y_vel {
+            if is_hit(initial_x_vel, initial_y_vel, target_area) {
+                result += 1;
+            }
+        }
+    }
+
+    result
+}
+
+/// Whether a probe launched with the given velocity is ever within the target area after a step.
+pub fn is_hit(mut x_vel: isize, mut y_vel: isize, target_area: TargetArea) -> bool {
+    let ((target_min_x, target_max_x), (target_min_y, target_max_y)) = target_area;
+
+    let (mut x, mut y) = (0, 0);
+    let target_range_x = target_min_x..=target_max_x;
+    let target_range_y = target_min_y..=target_max_y;
+
+    while x <= target_max_x && y >= target_min_y {
+        if target_range_x.contains(&x) && target_range_y.contains(&y) {
+            return true;
+        }
+
+        x += x_vel;
+        y += y_vel;
+        if x_vel != 0 {
+            if x_vel < 0 {
+                x_vel += 1;
+            } else {
+                x_vel -= 1;
+            }
+        }
+        y_vel -= 1;
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_parse() {
+        let target_area = parse(TEST_INPUT.as_bytes()).unwrap();
+        assert_eq!(((20, 30), (-10, -5)), target_area);
+
+        let err = parse("target area: x=20..30, y=-10..x".as_bytes()).unwrap_err();
+        assert_eq!("1:31: expected a number, found \"x\"", err.to_string());
+    }
+
+    #[test]
+    fn test_part_1() {
+        let target_area = parse(TEST_INPUT.as_bytes()).unwrap();
+        let res = highest_y_position(target_area);
+        assert_eq!(Ok(45), res);
+
+        // no x velocity stops the probe above this area, so it must be hit on the way up.
+        let target_area = parse("target area: x=4..5, y=-10..-5".as_bytes()).unwrap();
+        assert_eq!(Ok(0), highest_y_position(target_area));
+        assert_eq!(reference::highest_y_position(target_area), highest_y_position(target_area));
+    }
+
+    #[test]
+    fn test_missed_area() {
+        let err = highest_y_position(((5, 4), (-10, -5))).unwrap_err();
+        assert_eq!("no trajectory hits the target area", err.to_string());
+        assert_eq!(Err(err), reference::highest_y_position(((5, 4), (-10, -5))));
+    }
+
+    #[test]
+    fn test_furthest() {
+        let target_area = parse("target area: x=499..500, y=-500..-499".as_bytes()).unwrap();
+        assert_eq!(Ok(0), highest_y_position(target_area));
+        assert_eq!(8, number_of_accurate_initial_velocities(target_area));
+
+        let err = parse("target area: x=1000000..2000000, y=-1000000..-5".as_bytes()).unwrap_err();
+        assert_eq!(
+            "1:1: expected a target area within 500 of the launch position, \
+             found \"target area: x=1000000..2000000, y=-1000000..-5\"",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        let target_area = parse(TEST_INPUT.as_bytes()).unwrap();
+        let res = number_of_accurate_initial_velocities(target_area);
+        assert_eq!(112, res);
+    }
+}
@@ -0,0 +1,95 @@
+//! A slow but obviously correct solver, to check the fast one against.
+
+use crate::TargetArea;
+
+use aoc_2021_common::SolveError;
+
+/// Highest y position reached by any hitting trajectory, found by flying every candidate velocity.
+pub fn highest_y_position(target_area: TargetArea) -> Result<isize, SolveError> {
+    hits(target_area)
+        .map(|(_, y_vel)| peak(y_vel))
+        .max()
+        .ok_or_else(|| SolveError::new("no trajectory hits the target area"))
+}
+
+/// Number of hitting velocities, found by flying every candidate velocity.
+pub fn number_of_accurate_initial_velocities(target_area: TargetArea) -> usize {
+    hits(target_area).count()
+}
+
+/// Every initial velocity that hits the target area. Faster than the furthest edge of the area
+/// along x, or than its lowest edge along y in either direction, the probe overshoots on the first
+/// step or on its way back down through y = 0.
+fn hits(target_area: TargetArea) -> impl Iterator<Item = (isize, isize)> {
+    let ((_, max_x), (min_y, _)) = target_area;
+
+    (0..=max_x)
+        .flat_map(move |x_vel| (min_y..=-min_y).map(move |y_vel| (x_vel, y_vel)))
+        .filter(move |&velocity| hits_area(velocity, target_area))
+}
+
+/// Whether a probe launched with the given velocity is within the target area after some step,
+/// found by flying it until it is below the area and still falling.
+fn hits_area(velocity: (isize, isize), target_area: TargetArea) -> bool {
+    let ((min_x, max_x), (min_y, max_y)) = target_area;
+    let (mut x_vel, mut y_vel) = velocity;
+    let (mut x, mut y) = (0, 0);
+
+    while y >= min_y || y_vel >= 0 {
+        x += x_vel;
+        y += y_vel;
+        x_vel -= x_vel.signum();
+        y_vel -= 1;
+
+        if (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Highest y position reached with the given initial y velocity, by flying it step by step.
+fn peak(mut y_vel: isize) -> isize {
+    let mut y = 0;
+
+    while y_vel > 0 {
+        y += y_vel;
+        y_vel -= 1;
+    }
+
+    y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_example() {
+        let target_area = crate::parse(TEST_INPUT.as_bytes()).unwrap();
+        assert_eq!(Ok(45), highest_y_position(target_area));
+        assert_eq!(112, number_of_accurate_initial_velocities(target_area));
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_solver(seed in any::<u64>(), size in 1..50usize) {
+            let target_area = crate::parse(generate(seed, size).as_bytes()).unwrap();
+
+            prop_assert_eq!(
+                highest_y_position(target_area),
+                crate::highest_y_position(target_area)
+            );
+            prop_assert_eq!(
+                number_of_accurate_initial_velocities(target_area),
+                crate::number_of_accurate_initial_velocities(target_area)
+            );
+        }
+    }
+}
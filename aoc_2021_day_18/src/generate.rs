@@ -0,0 +1,52 @@
+use aoc_2021_common::generate::rng;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// Number of snailfish numbers in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 100;
+
+/// `size` snailfish numbers, nested up to four pairs deep.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let mut result = String::new();
+
+    for _ in 0..size.max(1) {
+        element(&mut rng, 0, &mut result);
+        result.push('\n');
+    }
+
+    result
+}
+
+/// Appends a pair or a regular number nested `depth` pairs deep. The outermost element is always a
+/// pair.
+fn element(rng: &mut StdRng, depth: u32, result: &mut String) {
+    if depth == 0 || (depth < 4 && rng.gen_bool(0.6)) {
+        result.push('[');
+        element(rng, depth + 1, result);
+        result.push(',');
+        element(rng, depth + 1, result);
+        result.push(']');
+    } else {
+        result.push(rng.gen_range('0'..='9'));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day18;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day18::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day18::part_1(&input).unwrap();
+            Day18::part_2(&input).unwrap();
+        }
+    }
+}
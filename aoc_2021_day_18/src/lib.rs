@@ -0,0 +1,304 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines, unexpected_end, Line};
+use aoc_2021_common::{ParseError, Solution, SolveError};
+
+use std::io::BufRead;
+use std::iter::{Peekable, Sum};
+use std::ops::Add;
+use std::str::{CharIndices, FromStr};
+
+/// Day 18: Snailfish.
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Vec<VecTree>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(solve_1(input.clone()))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(solve_2(input.clone()))
+    }
+}
+
+/// Parses one snailfish number per line.
+pub fn parse(input: impl BufRead) -> Result<Vec<VecTree>, ParseError> {
+    parse_lines(input, |lines| {
+        if lines.is_empty() {
+            return Err(unexpected_end(lines, "a snailfish number"));
+        }
+
+        numbered(lines).map(|line| VecTree::parse(&line)).collect()
+    })
+}
+
+/// Magnitude of the sum of every number.
+pub fn solve_1(input: Vec<VecTree>) -> u32 {
+    let sum: VecTree = input.into_iter().sum();
+
+    sum.magnitude()
+}
+
+/// Largest magnitude of the sum of any two different numbers.
+pub fn solve_2(input: Vec<VecTree>) -> u32 {
+    let mut result = 0;
+
+    for a in input.iter() {
+        for b in input.iter() {
+            if a == b { continue; }
+
+            let sum = a.clone() + b.clone();
+
+            result = result.max(sum.magnitude());
+        }
+    }
+
+    result
+}
+
+/// A snailfish number, stored as its regular numbers in order along with the depth at which each is
+/// nested.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VecTree {
+    vals: Vec<u32>,
+    depths: Vec<u32>,
+}
+
+impl VecTree {
+    /// Parses a line holding a pair, e.g. "[[1,2],3]", nested at most four deep.
+    pub fn parse(line: &Line) -> Result<Self, ParseError> {
+        let mut tree = Self { vals: vec![], depths: vec![] };
+        let mut chars = line.text.char_indices().peekable();
+
+        if chars.peek().map(|(_, c)| *c) != Some('[') {
+            return Err(Self::unexpected(line, &mut chars, "'['"));
+        }
+        tree.parse_element(line, &mut chars, 0)?;
+
+        if chars.peek().is_some() {
+            return Err(Self::unexpected(line, &mut chars, "the end of the line"));
+        }
+
+        Ok(tree)
+    }
+
+    fn parse_element(
+        &mut self,
+        line: &Line,
+        chars: &mut Peekable<CharIndices>,
+        depth: u32,
+    ) -> Result<(), ParseError> {
+        match chars.peek().map(|(_, c)| *c) {
+            Some('[') if depth < 4 => {
+                chars.next();
+                self.parse_element(line, chars, depth + 1)?;
+                Self::expect(line, chars, ',')?;
+                self.parse_element(line, chars, depth + 1)?;
+                Self::expect(line, chars, ']')?;
+            },
+            Some(c) if c.is_ascii_digit() => {
+                chars.next();
+                self.vals.push(c.to_digit(10).unwrap());
+                self.depths.push(depth);
+            },
+            _ => return Err(Self::unexpected(line, chars, "a regular number")),
+        }
+
+        Ok(())
+    }
+
+    fn expect(line: &Line, chars: &mut Peekable<CharIndices>, c: char) -> Result<(), ParseError> {
+        match chars.peek() {
+            Some((_, next)) if *next == c => {
+                chars.next();
+                Ok(())
+            },
+            _ => Err(Self::unexpected(line, chars, &format!("{:?}", c))),
+        }
+    }
+
+    /// An error pointing at the next character, or at the end of the line if there are none left.
+    fn unexpected(line: &Line, chars: &mut Peekable<CharIndices>, expected: &str) -> ParseError {
+        match chars.peek() {
+            Some((i, c)) => line.error(&line.text[*i..*i + c.len_utf8()], expected),
+            None => line.error_at(line.end(), expected),
+        }
+    }
+
+    fn reduce(&mut self) {
+        for i in 0..self.vals.len() {
+            if self.depths[i] == 5 {
+                self.explode(i);
+                self.reduce();
+                break;
+            }
+        }
+        for i in 0..self.vals.len() {
+            if self.vals[i] > 9 {
+                self.split(i);
+                self.reduce();
+                break;
+            }
+        }
+    }
+
+    fn split(&mut self, idx: usize) {
+        let l = self.vals[idx] / 2;
+        let r = self.vals[idx] - l;
+
+        self.vals[idx] = l;
+        self.depths[idx] += 1;
+
+        self.vals.insert(idx + 1, r);
+        self.depths.insert(idx + 1, self.depths[idx]);
+    }
+
+    fn explode(&mut self, idx: usize) {
+        let (l, r) = (self.vals[idx], self.vals[idx + 1]);
+
+        if idx > 0 {
+            self.vals[idx - 1] += l;
+        }
+        if idx < self.vals.len() - 2 {
+            self.vals[idx + 2] += r;
+        }
+
+        self.vals[idx + 1] = 0;
+        self.depths[idx + 1] -= 1;
+
+        self.vals.remove(idx);
+        self.depths.remove(idx);
+    }
+
+    pub fn magnitude(&self) -> u32 {
+        let mut vals = self.vals.clone();
+        let mut depths = self.depths.clone();
+        let mut merge_depth = 4;
+
+        while vals.len() > 1 {
+            let mut new_vals = vec![];
+            let mut new_depths = vec![];
+            let mut i = 0;
+
+            while i < vals.len() {
+                if depths[i] == merge_depth {
+                    new_vals.push(vals[i] * 3 + vals[i + 1] * 2);
+                    new_depths.push(depths[i] - 1);
+                    i += 2;
+                    continue;
+                }
+                new_vals.push(vals[i]);
+                new_depths.push(depths[i]);
+                i += 1;
+            }
+
+            vals = new_vals;
+            depths = new_depths;
+            merge_depth -= 1;
+        }
+
+        vals[0]
+    }
+}
+
+impl FromStr for VecTree {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(&Line { number: 1, text: s })
+    }
+}
+
+impl Add for VecTree {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self.vals.extend(other.vals);
+        self.depths.extend(other.depths);
+
+        for d in self.depths.iter_mut() {
+            *d += 1;
+        }
+
+        self.reduce();
+
+        self
+    }
+}
+
+impl Sum for VecTree {
+    fn sum<I>(mut iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        let mut sum = iter.next().unwrap();
+
+        for vt in iter {
+            sum = sum + vt;
+        }
+
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_magnitude_calc() {
+        let num_1 = VecTree::from_str("[[1,2],[[3,4],5]]").unwrap();
+        let num_2 = VecTree::from_str("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").unwrap();
+        let num_3 = VecTree::from_str("[[[[1,1],[2,2]],[3,3]],[4,4]]").unwrap();
+        let num_4 = VecTree::from_str("[[[[3,0],[5,3]],[4,4]],[5,5]]").unwrap();
+        let num_5 = VecTree::from_str("[[[[5,0],[7,4]],[5,5]],[6,6]]").unwrap();
+        let num_6 = VecTree::from_str("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").unwrap();
+        let num_7 = VecTree::from_str("[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]").unwrap();
+        let num_8 = VecTree::from_str("[[[5,[2,8]],4],[5,[[9,9],0]]]").unwrap();
+
+        assert_eq!(143, num_1.magnitude());
+        assert_eq!(1384, num_2.magnitude());
+        assert_eq!(445, num_3.magnitude());
+        assert_eq!(791, num_4.magnitude());
+        assert_eq!(1137, num_5.magnitude());
+        assert_eq!(3488, num_6.magnitude());
+        assert_eq!(1636, num_7.magnitude());
+        assert_eq!(1125, num_8.magnitude());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = VecTree::from_str("[[1,2],[3]]").unwrap_err();
+        assert_eq!("1:10: expected ',', found \"]\"", err.to_string());
+
+        let err = VecTree::from_str("[[[[[1,2],3],4],5],6]").unwrap_err();
+        assert_eq!("1:5: expected a regular number, found \"[\"", err.to_string());
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = parse(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve_1(input);
+        assert_eq!(4140, res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = parse(TEST_INPUT.as_bytes()).unwrap();
+        let res = solve_2(input);
+        assert_eq!(3993, res);
+    }
+}
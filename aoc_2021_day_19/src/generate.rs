@@ -0,0 +1,104 @@
+use crate::{Point, RANGE, ROTATIONS};
+
+use aoc_2021_common::generate::rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use std::collections::BTreeSet;
+
+/// Number of scanners in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 30;
+
+/// Number of beacons each scanner shares with the one it was placed next to, the fewest needed to
+/// locate it.
+const SHARED_BEACONS: usize = 12;
+
+/// Reports from `size` scanners, each facing a random way. Every scanner after the first overlaps
+/// an earlier one by at least 12 beacons, so they can all be located.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let mut scanners = vec![Point::default()];
+    let mut beacons = BTreeSet::new();
+
+    let own = rng.gen_range(0..10);
+    add_beacons(&mut rng, &mut beacons, Point::default(), Point::default(), own);
+
+    while scanners.len() < size {
+        let neighbour = *scanners.choose(&mut rng).unwrap();
+        let offset = Point::new(
+            rng.gen_range(-1200..=1200),
+            rng.gen_range(-1200..=1200),
+            rng.gen_range(-1200..=1200),
+        );
+        let scanner = neighbour + offset;
+        if scanners.contains(&scanner) {
+            continue;
+        }
+
+        add_beacons(&mut rng, &mut beacons, neighbour, scanner, SHARED_BEACONS);
+        let own = rng.gen_range(0..10);
+        add_beacons(&mut rng, &mut beacons, scanner, scanner, own);
+        scanners.push(scanner);
+    }
+
+    let mut result = String::new();
+
+    for (i, scanner) in scanners.iter().enumerate() {
+        let rotation = ROTATIONS.choose(&mut rng).unwrap();
+        let mut report = beacons.iter()
+            .filter(|b| in_range(scanner, b))
+            .map(|b| rotation.apply(&(*b - *scanner)))
+            .collect::<Vec<Point>>();
+        report.shuffle(&mut rng);
+
+        if i > 0 {
+            result.push('\n');
+        }
+        result.push_str(&format!("--- scanner {} ---\n", i));
+        for p in report {
+            result.push_str(&format!("{}\n", p));
+        }
+    }
+
+    result
+}
+
+fn in_range(scanner: &Point, beacon: &Point) -> bool {
+    let d = *beacon - *scanner;
+
+    d.x.abs() <= RANGE && d.y.abs() <= RANGE && d.z.abs() <= RANGE
+}
+
+/// Adds `n` new beacons that both scanners can see.
+fn add_beacons(rng: &mut StdRng, beacons: &mut BTreeSet<Point>, a: Point, b: Point, n: usize) {
+    let axis = |rng: &mut StdRng, a: i32, b: i32| rng.gen_range(a.max(b) - RANGE..=a.min(b) + RANGE);
+    let mut added = 0;
+
+    while added < n {
+        let beacon = Point::new(axis(rng, a.x, b.x), axis(rng, a.y, b.y), axis(rng, a.z, b.z));
+        if beacons.insert(beacon) {
+            added += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day19;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 5), generate(1, 5));
+
+        for seed in 0..5 {
+            let input = Day19::parse(generate(seed, 5).as_bytes()).unwrap();
+            assert_eq!(5, input.as_ref().unwrap().len());
+            Day19::part_1(&input).unwrap();
+            Day19::part_2(&input).unwrap();
+        }
+    }
+}
@@ -0,0 +1,371 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines, unexpected_end};
+use aoc_2021_common::{ParseError, Point3, Solution, SolveError};
+use lazy_static::lazy_static;
+use tracing::{debug, trace};
+
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+use std::mem;
+
+/// Day 19: Beacon Scanner.
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    /// The scanners, located once for both parts as that is nearly all the work, or why they
+    /// cannot all be.
+    type Input = Result<Vec<Scanner>, SolveError>;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        Ok(locate_all_scanners(parse(input)?))
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(total_unique_beacons(input.as_ref().map_err(Clone::clone)?))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(max_manhattan_distance(input.as_ref().map_err(Clone::clone)?))
+    }
+}
+
+/// How far a scanner can see along each axis.
+pub const RANGE: i32 = 1000;
+
+/// Parses the beacons reported by each scanner, relative to that scanner.
+pub fn parse(input: impl BufRead) -> Result<Vec<Scanner>, ParseError> {
+    parse_lines(input, |lines| {
+        let mut result = vec![];
+        let mut points = vec![];
+        let mut in_scanner = false;
+
+        for line in numbered(lines) {
+            if !in_scanner {
+                if !(line.text.starts_with("--- scanner ") && line.text.ends_with(" ---")) {
+                    return Err(line.error(line.text, "\"--- scanner N ---\""));
+                }
+                in_scanner = true;
+                continue;
+            }
+            if line.text.is_empty() {
+                result.push(Scanner::new(mem::take(&mut points)));
+                in_scanner = false;
+                continue;
+            }
+
+            let (x, yz) = line.split_once(",")?;
+            let (y, z) = yz.split_once(',')
+                .ok_or_else(|| line.error_at(line.end(), "\",\""))?;
+
+            let coordinate = |text, expected| match line.parse::<i32>(text, expected)? {
+                c if c.abs() <= RANGE => Ok(c),
+                _ => Err(line.error(text, expected)),
+            };
+            points.push(Point::new(
+                coordinate(x, "an x coordinate from -1000 to 1000")?,
+                coordinate(y, "a y coordinate from -1000 to 1000")?,
+                coordinate(z, "a z coordinate from -1000 to 1000")?,
+            ));
+        }
+
+        if in_scanner {
+            result.push(Scanner::new(mem::take(&mut points)));
+        }
+        if result.is_empty() {
+            return Err(unexpected_end(lines, "a scanner report"));
+        }
+
+        Ok(result)
+    })
+}
+
+/// Locates every scanner relative to scanner 0, converting their beacons to the same coordinates.
+/// Fails if some scanners do not overlap enough with the others to be located.
+pub fn locate_all_scanners(mut scanners: Vec<Scanner>) -> Result<Vec<Scanner>, SolveError> {
+    let mut located_scanners = vec![];
+
+    // scanner 0 is the origin.
+    if let Some(origin) = scanners.first_mut() {
+        origin.location = Some(Point::default());
+    }
+
+    while !scanners.is_empty() {
+        let reference_scanner = find_next_reference_scanner(&mut scanners).ok_or_else(|| {
+            SolveError::new(format!(
+                "{} of {} scanners cannot be located",
+                scanners.len(),
+                scanners.len() + located_scanners.len()
+            ))
+        })?;
+
+        for scanner in scanners.iter_mut() {
+            if scanner.location.is_some() { continue; }
+
+            let located = scanner.try_to_locate(&reference_scanner);
+            trace!(
+                reference = ?reference_scanner.location.unwrap(),
+                beacons = scanner.local_beacons.len(),
+                located,
+                "alignment attempt"
+            );
+        }
+
+        located_scanners.push(reference_scanner);
+    }
+
+    Ok(located_scanners)
+}
+
+/// Takes out a located scanner that has not yet been used to locate the others, if any are left.
+fn find_next_reference_scanner(scanners: &mut Vec<Scanner>) -> Option<Scanner> {
+    let i = scanners.iter().position(|s| s.location.is_some())?;
+
+    Some(scanners.remove(i))
+}
+
+/// Largest Manhattan distance between any two located scanners.
+pub fn max_manhattan_distance(located_scanners: &[Scanner]) -> i32 {
+    let mut result = 0;
+
+    for a in located_scanners.iter() {
+        for b in located_scanners.iter() {
+            if a == b { continue; }
+
+            result = result.max(a.location.unwrap().manhattan_distance(&b.location.unwrap()));
+        }
+    }
+
+    result
+}
+
+/// Number of distinct beacons seen by the located scanners.
+pub fn total_unique_beacons(located_scanners: &[Scanner]) -> usize {
+    let mut unique_beacons = HashSet::new();
+
+    for s in located_scanners.iter() {
+        for beacon in s.global_beacons.iter() {
+            unique_beacons.insert(beacon);
+        }
+    }
+
+    unique_beacons.len()
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scanner {
+    local_beacons: Vec<Point>,
+    global_beacons: Vec<Point>,
+    location: Option<Point>,
+}
+
+impl Scanner {
+    pub fn new(local_beacons: Vec<Point>) -> Self {
+        Self {
+            global_beacons: local_beacons.clone(),
+            local_beacons,
+            location: None,
+        }
+    }
+
+    /// Position relative to scanner 0, once located.
+    pub fn location(&self) -> Option<&Point> {
+        self.location.as_ref()
+    }
+
+    /// Beacon positions, relative to scanner 0 once located.
+    pub fn beacons(&self) -> &[Point] {
+        &self.global_beacons
+    }
+
+    fn rotate(&mut self, rotation: &Rotation) {
+        for (loc_pt, glob_pt) in self.local_beacons.iter().zip(self.global_beacons.iter_mut()) {
+            *glob_pt = rotation.apply(loc_pt);
+        }
+    }
+
+    fn translate(&mut self, translation: &Translation) {
+        for glob_pt in self.global_beacons.iter_mut() {
+            *glob_pt += *translation;
+        }
+    }
+
+    fn _transform(&mut self, rotation: &Rotation, translation: &Translation) {
+        for (loc_pt, glob_pt) in self.local_beacons.iter().zip(self.global_beacons.iter_mut()) {
+            *glob_pt = rotation.apply(loc_pt) + *translation;
+        }
+    }
+
+    /// Attempts to locate the scanner from at least 12 beacons it shares with an already located
+    /// scanner.
+    pub fn try_to_locate(&mut self, reference_scanner: &Scanner) -> bool {
+        for rotation in ROTATIONS.iter() {
+            self.rotate(rotation);
+
+            let (reference, own) = (&reference_scanner.global_beacons, &self.global_beacons);
+
+            if let Some(translation) = overlap_translation(reference, own) {
+                self.location = Some(translation);
+                debug!(location = ?translation, "scanner located");
+
+                self.translate(&translation);
+
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// The translation moving enough of beacons `b` onto beacons `a` to be sure they are the same. Each
+/// pair of beacons votes for the translation that lines them up, so it takes one pass over the
+/// pairs rather than one per pair.
+fn overlap_translation(a: &[Point], b: &[Point]) -> Option<Translation> {
+    let mut votes = HashMap::new();
+
+    for point_a in a {
+        for point_b in b {
+            let translation = *point_a - *point_b;
+            let count = votes.entry(translation).or_insert(0);
+
+            *count += 1;
+            if *count >= 6 {
+                return Some(translation);
+            }
+        }
+    }
+
+    None
+}
+
+pub type Point = Point3<i32>;
+type Translation = Point3<i32>;
+
+/// A 3x3 rotation matrix, by rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rotation([[i32; 3]; 3]);
+
+impl Rotation {
+    const IDENTITY: Rotation = Rotation([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+
+    fn apply(&self, p: &Point) -> Point {
+        let [x, y, z] = self.0.map(|[a, b, c]| a * p.x + b * p.y + c * p.z);
+
+        Point::new(x, y, z)
+    }
+
+    /// The rotation applying `other` and then `self`.
+    fn compose(&self, other: &Rotation) -> Rotation {
+        Rotation(self.0.map(|row| {
+            [0, 1, 2].map(|col| (0..3).map(|k| row[k] * other.0[k][col]).sum())
+        }))
+    }
+}
+
+lazy_static! {
+    static ref ROTATIONS: Vec<Rotation> = generate_rotation_matrices();
+}
+
+fn generate_rotation_matrices() -> Vec<Rotation> {
+    let first = [
+        Rotation::IDENTITY,
+        Rotation([[0, 1, 0],
+                  [0, 0, 1],
+                  [1, 0, 0]]),
+        Rotation([[0, 0, 1],
+                  [1, 0, 0],
+                  [0, 1, 0]]),
+    ];
+
+    let second = [
+        Rotation::IDENTITY,
+        Rotation([[-1, 0, 0],
+                  [0, -1, 0],
+                  [0, 0, 1]]),
+        Rotation([[-1, 0, 0],
+                  [0, 1, 0],
+                  [0, 0, -1]]),
+        Rotation([[1, 0, 0],
+                  [0, -1, 0],
+                  [0, 0, -1]]),
+    ];
+
+    let third = [
+        Rotation::IDENTITY,
+        Rotation([[0, 0, -1],
+                  [0, -1, 0],
+                  [-1, 0, 0]]),
+    ];
+
+    let mut result = vec![];
+
+    for a in first.iter() {
+        for b in second.iter() {
+            for c in third.iter() {
+                result.push(a.compose(&b.compose(c)));
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        let scanners = parse(TEST_INPUT.as_bytes()).unwrap();
+        let located_scanners = locate_all_scanners(scanners).unwrap();
+        let res = total_unique_beacons(&located_scanners);
+        assert_eq!(79, res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let scanners = parse(TEST_INPUT.as_bytes()).unwrap();
+        let located_scanners = locate_all_scanners(scanners).unwrap();
+        let res = max_manhattan_distance(&located_scanners);
+        assert_eq!(3621, res);
+    }
+
+    #[test]
+    fn test_unlocatable_scanner() {
+        // the second scanner shares no beacons with the first.
+        let input = "--- scanner 0 ---\n0,0,0\n1,2,3\n\n--- scanner 1 ---\n500,500,500\n";
+        let located = Day19::parse(input.as_bytes()).unwrap();
+
+        let err = Day19::part_1(&located).unwrap_err();
+        assert_eq!("1 of 2 scanners cannot be located", err.to_string());
+        assert_eq!(Err(err), Day19::part_2(&located));
+    }
+
+    #[test]
+    fn test_moved_beacon() {
+        // a beacon the first scanner reports, moved elsewhere within its range.
+        let input = generate(290924694439962769, 5)
+            .replacen("\n-933,334,-956\n", "\n-33,334,-956\n", 1);
+        let located = Day19::parse(input.as_bytes()).unwrap();
+
+        assert_eq!(Ok(65), Day19::part_1(&located));
+        assert_eq!(Ok(1595), Day19::part_2(&located));
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let err = parse("--- scanner 0 ---\n0,0,0\n1,-1001,3\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            "3:3: expected a y coordinate from -1000 to 1000, found \"-1001\"",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use aoc_2021_common::generate::{char_grid, rng};
+use rand::Rng;
+
+/// Width and height of the image in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 100;
+
+/// A random enhancement algorithm followed by a `size` by `size` image. As in the real puzzle input,
+/// if the algorithm lights the background it darkens it again on the next pass.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let mut pixel = || if rng.gen_bool(0.5) { '#' } else { '.' };
+
+    let mut algo = char_grid(1, 512, &mut pixel);
+    if algo.starts_with('#') {
+        algo.replace_range(511..512, ".");
+    }
+    let image = char_grid(size.max(1), size.max(1), &mut pixel);
+
+    format!("{}\n{}", algo, image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day20;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 10), generate(1, 10));
+
+        for seed in 0..5 {
+            let input = Day20::parse(generate(seed, 10).as_bytes()).unwrap();
+            Day20::part_1(&input).unwrap();
+            Day20::part_2(&input).unwrap();
+        }
+    }
+}
@@ -0,0 +1,270 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines, unexpected_end};
+use aoc_2021_common::{Grid, Param, Params, ParseError, Solution, SolveError};
+
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::BufRead;
+
+/// Day 20: Trench Map.
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "passes_1",
+            default: 2,
+            min: 0,
+            max: 500,
+            about: "enhancement passes for part one",
+        },
+        Param {
+            name: "passes_2",
+            default: 50,
+            min: 0,
+            max: 500,
+            about: "enhancement passes for part two",
+        },
+    ];
+
+    type Input = Image;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Self::part_1_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Self::part_2_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part_1_with(input: &Self::Input, params: &Params) -> Result<Self::Answer1, SolveError> {
+        let mut img = input.clone();
+
+        for _ in 0..params.get("passes_1") {
+            img.enhance();
+        }
+
+        img.total_lit_pixels()
+    }
+
+    fn part_2_with(input: &Self::Input, params: &Params) -> Result<Self::Answer2, SolveError> {
+        let mut img = input.clone();
+
+        for _ in 0..params.get("passes_2") {
+            img.enhance();
+        }
+
+        img.total_lit_pixels()
+    }
+}
+
+/// Parses the image enhancement algorithm followed by the input image.
+pub fn parse(input: impl BufRead) -> Result<Image, ParseError> {
+    parse_lines(input, |lines| {
+        let mut lines_iter = numbered(lines);
+
+        let algo_line = lines_iter.next()
+            .ok_or_else(|| unexpected_end(lines, "an enhancement algorithm"))?;
+        let algo = Grid::parse_chars(Some(algo_line), "'#' or '.'", pixel)?;
+        if algo.len() != ALGO_LEN {
+            let expected = format!("an enhancement algorithm of {} pixels", ALGO_LEN);
+            return Err(algo_line.error_at(1, expected));
+        }
+
+        match lines_iter.next() {
+            Some(line) if line.text.is_empty() => (),
+            Some(line) => return Err(line.error(line.text, "a blank line")),
+            None => return Err(unexpected_end(lines, "a blank line")),
+        }
+
+        let pixels = Grid::parse_chars(lines_iter, "'#' or '.'", pixel)?;
+
+        Ok(Image::new(pixels, algo.row(0).to_vec()))
+    })
+}
+
+/// Whether a pixel is lit.
+fn pixel(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+/// Number of entries in the enhancement algorithm, one for each 3x3 square of pixels.
+const ALGO_LEN: usize = 512;
+
+/// An image surrounded by infinitely many pixels, which are all lit or all dark.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "ImageData"))]
+pub struct Image {
+    pixels: Grid<bool>,
+    /// Whether the pixels beyond the grid are lit.
+    background: bool,
+    enhancement_algo: Vec<bool>,
+}
+
+/// An image as serialized, checked to have a whole enhancement algorithm before being loaded.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct ImageData {
+    pixels: Grid<bool>,
+    background: bool,
+    enhancement_algo: Vec<bool>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ImageData> for Image {
+    type Error = String;
+
+    fn try_from(data: ImageData) -> Result<Self, String> {
+        if data.enhancement_algo.len() != ALGO_LEN {
+            return Err(format!(
+                "expected an enhancement algorithm of {} pixels, found {}",
+                ALGO_LEN,
+                data.enhancement_algo.len()
+            ));
+        }
+
+        let ImageData { pixels, background, enhancement_algo } = data;
+
+        Ok(Self { pixels, background, enhancement_algo })
+    }
+}
+
+impl Image {
+    /// Builds an image on a dark background from its pixels and the 512 entry enhancement
+    /// algorithm.
+    pub fn new(pixels: Grid<bool>, enhancement_algo: Vec<bool>) -> Self {
+        Self { pixels, background: false, enhancement_algo }
+    }
+
+    /// Number of lit pixels, which fails if the background is lit.
+    pub fn total_lit_pixels(&self) -> Result<usize, SolveError> {
+        if self.background {
+            return Err(SolveError::new("infinitely many lit pixels"));
+        }
+
+        Ok(self.pixels.iter().filter(|(_, lit)| **lit).count())
+    }
+
+    /// Applies a single pass of the enhancement algorithm.
+    pub fn enhance(&mut self) {
+        // each pass can only affect the pixels bordering the grid, so grow it by one on each side.
+        let pixels = Grid::from_fn(self.pixels.rows() + 2, self.pixels.cols() + 2, |row, col| {
+            self.get_enhanced_pixel(row as isize - 1, col as isize - 1)
+        });
+
+        self.background = self.enhancement_algo[if self.background { ALGO_LEN - 1 } else { 0 }];
+        self.pixels = pixels;
+    }
+
+    fn get_enhanced_pixel(&self, row: isize, col: isize) -> bool {
+        let idx = self.pixel_to_binary(row, col);
+
+        self.enhancement_algo[idx]
+    }
+
+    fn pixel_to_binary(&self, row: isize, col: isize) -> usize {
+        let mut result = 0;
+        let mut idx = 8;
+
+        for r in (row - 1)..=(row + 1) {
+            for c in (col - 1)..=(col + 1) {
+                if *self.pixels.get_signed(r, c).unwrap_or(&self.background) {
+                    result += 1 << idx;
+                }
+                idx -= 1;
+            }
+        }
+
+        result
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.pixels.map(|lit| if *lit { '#' } else { '.' }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    const ALGO: &str = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..##\
+                        #..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###\
+                        .######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#.\
+                        .#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#.....\
+                        .#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#..\
+                        ...####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.....\
+                        ..##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#";
+
+    #[test]
+    fn test_pixel_enhancement() {
+        let img_strings = vec![
+            String::from("#..#."),
+            String::from("#...."),
+            String::from("##..#"),
+            String::from("..#.."),
+            String::from("..###"),
+        ];
+        let pixels = Grid::parse_chars(numbered(&img_strings), "a pixel", pixel).unwrap();
+        let algo = ALGO.chars().map(|c| c == '#').collect();
+
+        let img = Image::new(pixels, algo);
+
+        assert_eq!(34, img.pixel_to_binary(2, 2));
+        assert!(img.get_enhanced_pixel(2, 2));
+    }
+
+    #[test]
+    fn test_part_1() {
+        let mut img = parse(TEST_INPUT.as_bytes()).unwrap();
+        println!("ORIGINAL:\n{}", img);
+        for i in 0..2 {
+            img.enhance();
+            println!("PASS {}:\n{}", i, img);
+        }
+        assert_eq!(Ok(35), img.total_lit_pixels());
+    }
+
+    #[test]
+    fn test_part_2() {
+        let mut img = parse(TEST_INPUT.as_bytes()).unwrap();
+        println!("ORIGINAL:\n{}", img);
+        for i in 0..50 {
+            img.enhance();
+            println!("PASS {}:\n{}", i, img);
+        }
+        assert_eq!(Ok(3351), img.total_lit_pixels());
+    }
+
+    #[test]
+    fn test_lit_background() {
+        // the first entry lights every dark square of pixels, the background included.
+        let input = format!("#{}\n\n#.\n", ".".repeat(511));
+        let img = parse(input.as_bytes()).unwrap();
+
+        let params = Params::parse(Day20::PARAMS, &["passes_1=1".to_string()]).unwrap();
+        let err = Day20::part_1_with(&img, &params).unwrap_err();
+        assert_eq!("infinitely many lit pixels", err.to_string());
+
+        let params = Params::parse(Day20::PARAMS, &["passes_1=2".to_string()]).unwrap();
+        assert_eq!(Ok(1), Day20::part_1_with(&img, &params));
+    }
+}
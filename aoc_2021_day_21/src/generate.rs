@@ -0,0 +1,35 @@
+use aoc_2021_common::generate::rng;
+use rand::Rng;
+
+/// The input is always two starting positions, so its size is ignored.
+pub const DEFAULT_SIZE: usize = 2;
+
+/// Random starting positions for both players. `size` is ignored.
+pub fn generate(seed: u64, _size: usize) -> String {
+    let mut rng = rng(seed);
+
+    format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+        rng.gen_range(1..=10),
+        rng.gen_range(1..=10)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day21;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 2), generate(1, 2));
+
+        for seed in 0..5 {
+            let input = Day21::parse(generate(seed, 2).as_bytes()).unwrap();
+            Day21::part_1(&input).unwrap();
+            Day21::part_2(&input).unwrap();
+        }
+    }
+}
@@ -0,0 +1,175 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines, unexpected_end};
+use aoc_2021_common::{ParseError, Solution, SolveError};
+
+use std::cmp;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+/// Day 21: Dirac Dice.
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    /// Starting positions of players 1 and 2.
+    type Input = (u8, u8);
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        let (p0_start, p1_start) = *input;
+        Ok(play_1(p0_start as usize, p1_start as usize))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        let (p0_start, p1_start) = *input;
+        Ok(play_2(p0_start, p1_start))
+    }
+}
+
+/// Parses the starting positions of players 1 and 2, one per line, e.g.
+/// "Player 1 starting position: 4".
+pub fn parse(input: impl BufRead) -> Result<(u8, u8), ParseError> {
+    parse_lines(input, |lines| {
+        let mut lines_iter = numbered(lines);
+        let mut positions = [0; 2];
+
+        for (i, position) in positions.iter_mut().enumerate() {
+            let prefix = format!("Player {} starting position: ", i + 1);
+
+            let line = lines_iter.next().ok_or_else(|| unexpected_end(lines, prefix.trim_end()))?;
+            let pos = line.text.strip_prefix(&prefix)
+                .ok_or_else(|| line.error(line.text, format!("{:?}", prefix)))?;
+
+            *position = line.parse(pos, "a position from 1 to 10")?;
+            if !(1..=10).contains(position) {
+                return Err(line.error(pos, "a position from 1 to 10"));
+            }
+        }
+
+        Ok((positions[0], positions[1]))
+    })
+}
+
+/// Number of universes in which the player who wins most often wins, playing with the Dirac dice.
+pub fn play_2(p0_start: u8, p1_start: u8) -> u64 {
+    fn helper(p0_score: u8, p1_score: u8, p0_pos: u8, p1_pos: u8, winners_cache: &mut HashMap<u32, (u64, u64)>) -> (u64, u64) {
+        if p1_score >= 21 {
+            return (0, 1);
+        }
+
+        let key = u32::from_be_bytes([p0_score, p1_score, p0_pos, p1_pos]);
+        if let Some(result) = winners_cache.get(&key) {
+            return *result;
+        }
+
+        let mut result = (0, 0);
+
+        for (roll, freq) in (3..=9).zip(&[1, 3, 6, 7, 6, 3, 1]) {
+            let new_p0_pos = (p0_pos + roll - 1) % 10 + 1;
+            let new_p0_score = p0_score + new_p0_pos;
+
+            let (p1_winners, p0_winners) =
+                helper(p1_score, new_p0_score, p1_pos, new_p0_pos, winners_cache);
+
+            result.0 += freq * p0_winners;
+            result.1 += freq * p1_winners;
+        }
+
+        winners_cache.insert(key, result);
+
+        result
+    }
+
+    let mut winners_cache: HashMap<u32, (u64, u64)> = HashMap::new();
+
+    let (p0_wins, p1_wins) = helper(0, 0, p0_start, p1_start, &mut winners_cache);
+
+    cmp::max(p0_wins, p1_wins)
+}
+
+/// Score of the losing player multiplied by the number of rolls, playing with the deterministic
+/// dice.
+pub fn play_1(p0_start: usize, p1_start: usize) -> usize {
+    let mut score_0 = 0;
+    let mut score_1 = 0;
+
+    let mut pos_0 = p0_start;
+    let mut pos_1 = p1_start;
+
+    let mut d = Dice::new();
+
+    loop {
+        pos_0 = (pos_0 + d.roll() - 1) % 10 + 1;
+        score_0 += pos_0;
+
+        if score_0 >= 1000 {
+            return score_1 * d.rolls;
+        }
+
+        pos_1 = (pos_1 + d.roll() - 1) % 10 + 1;
+        score_1 += pos_1;
+
+        if score_1 >= 1000 {
+            return score_0 * d.rolls;
+        }
+    }
+}
+
+struct Dice {
+    val: usize,
+    rolls: usize,
+}
+
+impl Dice {
+    fn new() -> Self {
+        Self { val: 1, rolls: 0 }
+    }
+
+    fn roll(&mut self) -> usize {
+        let mut score = 0;
+
+        for _ in 0..3 {
+            score += self.val;
+            self.val = self.val % 100 + 1;
+            self.rolls += 1;
+        }
+
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_parse() {
+        assert_eq!((4, 8), parse(TEST_INPUT.as_bytes()).unwrap());
+
+        let err = parse("Player 1 starting position: 11\n".as_bytes()).unwrap_err();
+        assert_eq!("1:29: expected a position from 1 to 10, found \"11\"", err.to_string());
+    }
+
+    #[test]
+    fn test_part_1() {
+        let (p0_start, p1_start) = parse(TEST_INPUT.as_bytes()).unwrap();
+        let res = play_1(p0_start as usize, p1_start as usize);
+        assert_eq!(739785, res);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let (p0_start, p1_start) = parse(TEST_INPUT.as_bytes()).unwrap();
+        let res = play_2(p0_start, p1_start);
+        assert_eq!(444356092776315, res);
+    }
+}
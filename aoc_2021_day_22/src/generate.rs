@@ -0,0 +1,64 @@
+use aoc_2021_common::generate::rng;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use std::ops::RangeInclusive;
+
+/// Number of reboot steps in the real puzzle input.
+pub const DEFAULT_SIZE: usize = 420;
+
+/// Number of leading steps within the initialization region, as in the real puzzle input.
+const INITIALIZATION_STEPS: usize = 20;
+
+/// `size` reboot steps, the first 20 of which lie within the -50..50 initialization region and the
+/// rest spread far beyond it. The first step turns cubes on.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let mut result = String::new();
+
+    for i in 0..size {
+        let state = if i == 0 || rng.gen_bool(0.7) { "on" } else { "off" };
+
+        let (extent, span) = if i < INITIALIZATION_STEPS {
+            (50, 10..=50)
+        } else {
+            (100_000, 5_000..=40_000)
+        };
+        let mut range = || axis(&mut rng, extent, span.clone());
+        let (x, y, z) = (range(), range(), range());
+
+        result.push_str(&format!(
+            "{} x={}..{},y={}..{},z={}..{}\n",
+            state, x.0, x.1, y.0, y.1, z.0, z.1
+        ));
+    }
+
+    result
+}
+
+/// An ascending range along one axis, between -`extent` and `extent`, of a length within `span`.
+fn axis(rng: &mut StdRng, extent: i64, span: RangeInclusive<i64>) -> (i64, i64) {
+    let len = rng.gen_range(span);
+    let min = rng.gen_range(-extent..=extent - len);
+
+    (min, min + len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day22;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 30), generate(1, 30));
+
+        for seed in 0..10 {
+            let input = Day22::parse(generate(seed, 30).as_bytes()).unwrap();
+            Day22::part_1(&input).unwrap();
+            Day22::part_2(&input).unwrap();
+        }
+    }
+}
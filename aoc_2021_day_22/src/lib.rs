@@ -0,0 +1,431 @@
+pub mod generate;
+pub mod reference;
+
+use aoc_2021_common::parse::{numbered, parse_lines};
+use aoc_2021_common::{Param, Params, ParseError, Point3, Solution, SolveError};
+use lazy_static::lazy_static;
+use regex::Regex;
+use tracing::trace;
+
+use std::cmp;
+use std::io::BufRead;
+use std::str::FromStr;
+
+/// Day 22: Reactor Reboot.
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "region",
+        default: 50,
+        min: 0,
+        max: 1_000_000_000,
+        about: "how far along each axis from the origin part one counts cubes",
+    }];
+
+    type Input = Vec<Command>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Self::part_1_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part_1_with(input: &Self::Input, params: &Params) -> Result<Self::Answer1, SolveError> {
+        let radius = params.try_get("region")?;
+
+        let mut reactor = Reactor::new();
+        reactor.process_commands(input);
+
+        Ok(reactor.total_powered_cubes_in_region(&Region::around_origin(radius)))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        let mut reactor = Reactor::new();
+        reactor.process_commands(input);
+
+        Ok(reactor.total_powered_cubes())
+    }
+}
+
+/// The cubes from -50 to 50 along each axis, which part one is limited to.
+pub const INITIALIZATION_REGION: Region = Region {
+    min: Point::new(-50, -50, -50),
+    max: Point::new(51, 51, 51),
+};
+
+/// The reactor core, tracked as a set of non-overlapping powered regions.
+pub struct Reactor {
+    powered_regions: Vec<Region>,
+}
+
+impl Default for Reactor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Reactor {
+    pub fn new() -> Self {
+        Self { powered_regions: vec![] }
+    }
+
+    pub fn process_commands(&mut self, commands: &[Command]) {
+        for cmd in commands {
+            self.process_cmd(cmd);
+        }
+    }
+
+    /// Turns the command's region on or off, splitting any powered regions it partially overlaps.
+    pub fn process_cmd(&mut self, cmd: &Command) {
+        // Find regions intersecting the command region, discard those fully enclosed by it.
+        let mut intersecting_regions = vec![];
+        let mut i = 0;
+        while i < self.powered_regions.len() {
+            if self.powered_regions[i].is_intersecting(&cmd.region) {
+                let r = self.powered_regions.remove(i);
+                if !r.is_enclosed_by(&cmd.region) {
+                    intersecting_regions.push(r);
+                }
+            } else {
+                i += 1;
+            }
+        }
+
+        for mut r in intersecting_regions {
+            let region = r;
+            if let Some(new_regions_vec) = r.split(&cmd.region.planes()) {
+                trace!(?region, pieces = new_regions_vec.len(), "split");
+                self.powered_regions.extend(new_regions_vec);
+            }
+        }
+
+        if cmd.state == State::On {
+            self.powered_regions.push(cmd.region);
+        }
+    }
+
+    pub fn total_powered_cubes(&self) -> i64 {
+        self.powered_regions.iter().fold(0, |acc, r| acc + r.total_cubes())
+    }
+
+    pub fn total_powered_cubes_in_region(&self, region: &Region) -> i64 {
+        let mut result = 0;
+        for r in self.powered_regions.iter().filter(|r| r.is_intersecting(region)) {
+            if r.is_enclosed_by(region) {
+                result += r.total_cubes();
+            } else {
+                let min = Point::new(
+                    cmp::max(r.min.x, region.min.x),
+                    cmp::max(r.min.y, region.min.y),
+                    cmp::max(r.min.z, region.min.z),
+                );
+                let max = Point::new(
+                    cmp::min(r.max.x, region.max.x),
+                    cmp::min(r.max.y, region.max.y),
+                    cmp::min(r.max.z, region.max.z),
+                );
+                let overlapping_region = Region::new(min, max);
+                result += overlapping_region.total_cubes();
+            }
+        }
+        result
+    }
+}
+
+/// A cuboid of cubes, from `min` inclusive to `max` exclusive.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Region {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Region {
+    /// The cubes from -`radius` to `radius` along each axis.
+    pub fn around_origin(radius: i64) -> Self {
+        let max = radius + 1;
+        Self::new(Point::new(-radius, -radius, -radius), Point::new(max, max, max))
+    }
+
+    pub fn new(min: Point, max: Point) -> Self {
+        Self { min, max }
+    }
+
+    pub fn is_enclosed_by(&self, other: &Region) -> bool {
+        self.min.x >= other.min.x && self.min.y >= other.min.y && self.min.z >= other.min.z
+            && self.max.x <= other.max.x && self.max.y <= other.max.y && self.max.z <= other.max.z
+    }
+
+    pub fn is_intersecting(&self, other: &Region) -> bool {
+        !(self.min.x >= other.max.x || self.min.y >= other.max.y || self.min.z >= other.max.z
+            || self.max.x <= other.min.x || self.max.y <= other.min.y || self.max.z <= other.min.z)
+    }
+
+    pub fn total_cubes(&self) -> i64 {
+        (self.max.x - self.min.x).abs() * (self.max.y - self.min.y).abs() * (self.max.z - self.min.z).abs()
+    }
+
+    fn split(&mut self, planes: &[Plane]) -> Option<Vec<Region>> {
+        let mut result = vec![];
+        for p in planes.iter() {
+            if let Some(new_region) = self.split_along_plane(p) {
+                result.push(new_region);
+            }
+        }
+
+        if result.is_empty() {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    fn split_along_plane(&mut self, plane: &Plane) -> Option<Region> {
+        match *plane {
+            Plane::Right(x) => {
+                if x > self.max.x || x < self.min.x {
+                    return None;
+                }
+
+                let new_region = Region::new(Point::new(x, self.min.y, self.min.z), self.max);
+                self.max.x = x;
+
+                Some(new_region)
+            },
+            Plane::Left(x) => {
+                if x > self.max.x || x < self.min.x {
+                    return None;
+                }
+
+                let new_region = Region::new(self.min, Point::new(x, self.max.y, self.max.z));
+                self.min.x = x;
+
+                Some(new_region)
+            },
+            Plane::Top(y) => {
+                if y > self.max.y || y < self.min.y {
+                    return None;
+                }
+
+                let new_region = Region::new(Point::new(self.min.x, y, self.min.z), self.max);
+                self.max.y = y;
+
+                Some(new_region)
+            },
+            Plane::Bottom(y) => {
+                if y > self.max.y || y < self.min.y {
+                    return None;
+                }
+
+                let new_region = Region::new(self.min, Point::new(self.max.x, y, self.max.z));
+                self.min.y = y;
+
+                Some(new_region)
+            },
+            Plane::Front(z) => {
+                if z > self.max.z || z < self.min.z {
+                    return None;
+                }
+
+                let new_region = Region::new(Point::new(self.min.x, self.min.y, z), self.max);
+                self.max.z = z;
+
+                Some(new_region)
+            },
+            Plane::Back(z) => {
+                if z > self.max.z || z < self.min.z {
+                    return None;
+                }
+
+                let new_region = Region::new(self.min, Point::new(self.max.x, self.max.y, z));
+                self.min.z = z;
+
+                Some(new_region)
+            },
+        }
+    }
+
+    fn planes(&self) -> Vec<Plane> {
+        vec![
+            Plane::Right(self.max.x),
+            Plane::Left(self.min.x),
+            Plane::Top(self.max.y),
+            Plane::Bottom(self.min.y),
+            Plane::Front(self.max.z),
+            Plane::Back(self.min.z),
+        ]
+    }
+}
+
+#[derive(Debug)]
+enum Plane {
+    // y-z plane, with x coord.
+    Right(i64), // max x
+    Left(i64), // min x
+    // x-z plane, with y coord.
+    Top(i64), // max y
+    Bottom(i64), // min y
+    // x-y plane, with z coord.
+    Front(i64), // max z
+    Back(i64), // min z
+}
+
+pub type Point = Point3<i64>;
+
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum State {
+    On,
+    Off,
+}
+
+impl FromStr for State {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "on" => Ok(State::On),
+            "off" => Ok(State::Off),
+            _ => Err("Invalid input."),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Command {
+    pub state: State,
+    pub region: Region,
+}
+
+/// The furthest a reboot step may reach from the origin along any axis, so that even the whole
+/// reactor holds fewer cubes than an `i64` can count.
+pub const MAX_COORDINATE: i64 = 1_000_000;
+
+/// Parses one reboot step per line, e.g. "on x=10..12,y=10..12,z=10..12".
+pub fn parse(input: impl BufRead) -> Result<Vec<Command>, ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"(?x)
+            ^
+            (?P<state>on|off)
+            \x20
+            x=(?P<x_min>-?\d+)\.\.(?P<x_max>-?\d+)
+            ,
+            y=(?P<y_min>-?\d+)\.\.(?P<y_max>-?\d+)
+            ,
+            z=(?P<z_min>-?\d+)\.\.(?P<z_max>-?\d+)
+            $
+        ").unwrap();
+    }
+
+    parse_lines(input, |lines| {
+        let mut result = vec![];
+
+        for line in numbered(lines) {
+            let caps = RE.captures(line.text)
+                .ok_or_else(|| {
+                    line.error(line.text, "a reboot step, e.g. \"on x=10..12,y=10..12,z=10..12\"")
+                })?;
+
+            let state = line.parse::<State>(&caps["state"], "\"on\" or \"off\"")?;
+
+            let expected = format!("a coordinate from -{0} to {0}", MAX_COORDINATE);
+            let bound = |name| match line.parse::<i64>(&caps[name], &expected)? {
+                c if (-MAX_COORDINATE..=MAX_COORDINATE).contains(&c) => Ok(c),
+                _ => Err(line.error(&caps[name], &expected)),
+            };
+            let (x_min, x_max) = (bound("x_min")?, bound("x_max")?);
+            let (y_min, y_max) = (bound("y_min")?, bound("y_max")?);
+            let (z_min, z_max) = (bound("z_min")?, bound("z_max")?);
+
+            if x_min > x_max || y_min > y_max || z_min > z_max {
+                return Err(line.error(&line.text[caps["state"].len() + 1..], "ascending ranges"));
+            }
+
+            let cmd = Command {
+                state,
+                region: Region::new(Point::new(x_min, y_min, z_min), Point::new(x_max + 1, y_max + 1, z_max + 1)),
+            };
+
+            result.push(cmd);
+        }
+
+        Ok(result)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_TEST_INPUT: &str = include_str!("../small_test_input.txt");
+    const TEST_INPUT_1: &str = include_str!("../test_input_1.txt");
+    const TEST_INPUT_2: &str = include_str!("../test_input_2.txt");
+
+    #[test]
+    fn test_small_input() {
+        let commands = parse(SMALL_TEST_INPUT.as_bytes()).unwrap();
+
+        let mut reactor = Reactor::new();
+        reactor.process_commands(&commands);
+
+        assert_eq!(39, reactor.total_powered_cubes());
+        assert_eq!(39, reactor.total_powered_cubes_in_region(&INITIALIZATION_REGION));
+    }
+
+    #[test]
+    fn test_larger_input() {
+        let commands = parse(TEST_INPUT_1.as_bytes()).unwrap();
+
+        let mut reactor = Reactor::new();
+        reactor.process_commands(&commands);
+
+        assert_eq!(39769202357779, reactor.total_powered_cubes());
+        assert_eq!(590784, reactor.total_powered_cubes_in_region(&INITIALIZATION_REGION));
+    }
+
+    #[test]
+    fn test_largest_input() {
+        let commands = parse(TEST_INPUT_2.as_bytes()).unwrap();
+
+        let mut reactor = Reactor::new();
+        reactor.process_commands(&commands);
+
+        assert_eq!(2758514936282235, reactor.total_powered_cubes());
+        assert_eq!(474140, reactor.total_powered_cubes_in_region(&INITIALIZATION_REGION));
+    }
+
+    #[test]
+    fn test_params() {
+        let commands = parse(TEST_INPUT_2.as_bytes()).unwrap();
+        let params = Params::parse(Day22::PARAMS, &["region=1000000000".to_string()]).unwrap();
+        assert_eq!(2758514936282235, Day22::part_1_with(&commands, &params).unwrap());
+
+        for region in ["region=9223372036854775807", "region=9223372036854775808"] {
+            assert!(Params::parse(Day22::PARAMS, &[region.to_string()]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_coordinate_bounds() {
+        let input = "on x=-1000000..1000000,y=-1000000..1000000,z=-1000000..1000000\n";
+        let commands = parse(input.as_bytes()).unwrap();
+        assert_eq!(Ok(8_000_012_000_006_000_001), Day22::part_2(&commands));
+
+        let err = parse("on x=0..1,y=0..1000001,z=0..1\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            "1:16: expected a coordinate from -1000000 to 1000000, found \"1000001\"",
+            err.to_string()
+        );
+
+        let err = parse("on x=0..1,y=0..1,z=0..9223372036854775807\n".as_bytes()).unwrap_err();
+        assert_eq!(1, err.line());
+    }
+}
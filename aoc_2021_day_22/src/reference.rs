@@ -0,0 +1,71 @@
+//! A slow but obviously correct solver, to check the fast one against.
+
+use crate::{Command, Point, Region, State};
+
+use aoc_2021_common::Bounds3;
+
+/// Number of cubes left on within `region`, found by switching every cube of each step one at a
+/// time. Only use this for small regions.
+pub fn cubes_on_in_region(commands: &[Command], region: &Region) -> i64 {
+    let size = region.max - region.min;
+    let index = |p: Point| (((p.x * size.y) + p.y) * size.z + p.z) as usize;
+
+    let mut cubes = vec![false; (size.x * size.y * size.z).max(0) as usize];
+
+    for cmd in commands {
+        for x in cmd.region.min.x.max(region.min.x)..cmd.region.max.x.min(region.max.x) {
+            for y in cmd.region.min.y.max(region.min.y)..cmd.region.max.y.min(region.max.y) {
+                for z in cmd.region.min.z.max(region.min.z)..cmd.region.max.z.min(region.max.z) {
+                    cubes[index(Point::new(x, y, z) - region.min)] = cmd.state == State::On;
+                }
+            }
+        }
+    }
+
+    cubes.iter().filter(|on| **on).count() as i64
+}
+
+/// Number of cubes left on anywhere, switching every cube in the region the steps span.
+pub fn cubes_on(commands: &[Command]) -> i64 {
+    let corners = commands.iter().flat_map(|c| [c.region.min, c.region.max]);
+
+    match Bounds3::of(corners) {
+        Some(bounds) => cubes_on_in_region(commands, &Region::new(bounds.min, bounds.max)),
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+    use crate::{Day22, INITIALIZATION_REGION};
+
+    use aoc_2021_common::Solution;
+    use proptest::prelude::*;
+
+    const TEST_INPUT_1: &str = include_str!("../test_input_1.txt");
+
+    #[test]
+    fn test_example() {
+        let commands = crate::parse(TEST_INPUT_1.as_bytes()).unwrap();
+        assert_eq!(590784, cubes_on_in_region(&commands, &INITIALIZATION_REGION));
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        // the first 20 generated steps lie within the initialization region, small enough to
+        // switch every cube.
+        #[test]
+        fn test_matches_solver(seed in any::<u64>(), size in 1..=20usize) {
+            let commands = crate::parse(generate(seed, size).as_bytes()).unwrap();
+
+            prop_assert_eq!(
+                cubes_on_in_region(&commands, &INITIALIZATION_REGION),
+                Day22::part_1(&commands).unwrap()
+            );
+            prop_assert_eq!(cubes_on(&commands), Day22::part_2(&commands).unwrap());
+        }
+    }
+}
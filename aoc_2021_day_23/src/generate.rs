@@ -0,0 +1,49 @@
+use crate::{least_energy, Amphipod, Burrow};
+
+use aoc_2021_common::generate::rng;
+use rand::seq::SliceRandom;
+
+/// The burrow always has two rows of four rooms, so its size is ignored.
+pub const DEFAULT_SIZE: usize = 8;
+
+/// A burrow with two amphipods of each type shuffled into its rooms. `size` is ignored.
+///
+/// Some shuffles leave the unfolded burrow impossible to organize, which real inputs never do, so
+/// those are shuffled again.
+pub fn generate(seed: u64, _size: usize) -> String {
+    let mut rng = rng(seed);
+
+    let mut amphipods = Amphipod::ALL.iter()
+        .chain(&Amphipod::ALL)
+        .copied()
+        .collect::<Vec<Amphipod>>();
+
+    loop {
+        amphipods.shuffle(&mut rng);
+
+        let burrow = Burrow { rooms: [0, 1, 2, 3].map(|i| vec![amphipods[i], amphipods[i + 4]]) };
+        if least_energy(&burrow.unfolded()).is_some() {
+            return burrow.to_string();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day23;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 8), generate(1, 8));
+
+        // seed 11 is first shuffled into a burrow that cannot be organized once unfolded.
+        for seed in [0, 11] {
+            let input = Day23::parse(generate(seed, 8).as_bytes()).unwrap();
+            Day23::part_1(&input).unwrap();
+            Day23::part_2(&input).unwrap();
+        }
+    }
+}
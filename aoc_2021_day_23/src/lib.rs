@@ -0,0 +1,512 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines, unexpected_end, Line};
+use aoc_2021_common::{ParseError, Solution, SolveError};
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::BufRead;
+
+/// Day 23: Amphipod.
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Burrow;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        least_energy(input).ok_or_else(cannot_be_organized)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        least_energy(&input.unfolded()).ok_or_else(cannot_be_organized)
+    }
+}
+
+/// For burrows whose amphipods block each other in for good, which only the search finds out.
+fn cannot_be_organized() -> SolveError {
+    SolveError::new("the amphipods cannot be organized")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Amphipod {
+    Amber,
+    Bronze,
+    Copper,
+    Desert,
+}
+
+impl Amphipod {
+    pub const ALL: [Amphipod; 4] =
+        [Amphipod::Amber, Amphipod::Bronze, Amphipod::Copper, Amphipod::Desert];
+
+    /// Energy taken to move a single step.
+    pub fn energy(self) -> u32 {
+        match self {
+            Amphipod::Amber => 1,
+            Amphipod::Bronze => 10,
+            Amphipod::Copper => 100,
+            Amphipod::Desert => 1000,
+        }
+    }
+
+    /// The room the amphipod belongs in, counting from the left.
+    pub fn room(self) -> usize {
+        self as usize
+    }
+
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'A' => Some(Amphipod::Amber),
+            'B' => Some(Amphipod::Bronze),
+            'C' => Some(Amphipod::Copper),
+            'D' => Some(Amphipod::Desert),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        (b'A' + self as u8) as char
+    }
+}
+
+/// The amphipods in each of the four rooms, from top to bottom, with everyone out of the hallway.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "BurrowData"))]
+pub struct Burrow {
+    rooms: [Vec<Amphipod>; 4],
+}
+
+/// A burrow as serialized, checked to be one the search handles before being loaded.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct BurrowData {
+    rooms: [Vec<Amphipod>; 4],
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<BurrowData> for Burrow {
+    type Error = String;
+
+    fn try_from(data: BurrowData) -> Result<Self, String> {
+        Self::new(data.rooms)
+    }
+}
+
+/// The rows hidden by the fold in the diagram, uncovered for part two.
+const FOLDED_ROWS: [[Amphipod; 4]; 2] = {
+    use Amphipod::*;
+    [[Desert, Copper, Bronze, Amber], [Desert, Bronze, Amber, Copper]]
+};
+
+impl Burrow {
+    /// A burrow with the given amphipods in each room, from top to bottom. Fails unless the rooms
+    /// are all of the same depth, shallow enough for the burrow to be unfolded, and hold as many
+    /// amphipods of each type as fit in a room.
+    pub fn new(rooms: [Vec<Amphipod>; 4]) -> Result<Self, String> {
+        let depth = rooms[0].len();
+        let max_depth = MAX_DEPTH - FOLDED_ROWS.len();
+
+        if depth == 0 || depth > max_depth || rooms.iter().any(|room| room.len() != depth) {
+            return Err(format!("rooms must all be of the same depth, from 1 to {}", max_depth));
+        }
+
+        for amphipod in Amphipod::ALL {
+            let count = rooms.iter().flatten().filter(|a| **a == amphipod).count();
+            if count != depth {
+                return Err(format!(
+                    "expected {} amphipods of type {}, found {}",
+                    depth,
+                    amphipod.to_char(),
+                    count
+                ));
+            }
+        }
+
+        Ok(Self { rooms })
+    }
+
+    pub fn rooms(&self) -> &[Vec<Amphipod>; 4] {
+        &self.rooms
+    }
+
+    /// Number of amphipods that fit in each room.
+    pub fn depth(&self) -> usize {
+        self.rooms[0].len()
+    }
+
+    /// The burrow with the folded rows inserted after the top row of rooms.
+    pub fn unfolded(&self) -> Self {
+        let mut rooms = self.rooms.clone();
+
+        for (i, room) in rooms.iter_mut().enumerate() {
+            room.splice(1..1, FOLDED_ROWS.iter().map(|row| row[i]));
+        }
+
+        Self { rooms }
+    }
+}
+
+/// Draws the burrow as in the puzzle input.
+impl fmt::Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "#############")?;
+        writeln!(f, "#...........#")?;
+
+        for row in 0..self.depth() {
+            let (start, end) = if row == 0 { ("###", "###") } else { ("  #", "#") };
+            let amphipods = self.rooms.iter().map(|room| room[row].to_char().to_string());
+
+            writeln!(f, "{}{}{}", start, amphipods.collect::<Vec<String>>().join("#"), end)?;
+        }
+
+        writeln!(f, "  #########")
+    }
+}
+
+/// Parses a diagram of the burrow with two rows of rooms and an empty hallway, e.g.
+///
+/// ```text
+/// #############
+/// #...........#
+/// ###B#C#B#D###
+///   #A#D#C#A#
+///   #########
+/// ```
+pub fn parse(input: impl BufRead) -> Result<Burrow, ParseError> {
+    parse_lines(input, |lines| {
+        let mut lines_iter = numbered(lines);
+        let mut next = |expected: &str| {
+            lines_iter.next().ok_or_else(|| unexpected_end(lines, expected))
+        };
+
+        expect_line(&next("the top wall")?, "#############", "the top wall")?;
+        expect_line(&next("the hallway")?, "#...........#", "an empty hallway")?;
+        let top = parse_row(&next("the top row of rooms")?, "###?#?#?#?###")?;
+        let bottom_line = next("the bottom row of rooms")?;
+        let bottom = parse_row(&bottom_line, "  #?#?#?#?#")?;
+        expect_line(&next("the bottom wall")?, "  #########", "the bottom wall")?;
+
+        if let Some(line) = lines_iter.find(|l| !l.text.trim().is_empty()) {
+            return Err(line.error(line.text, "the end of the burrow"));
+        }
+
+        let rooms = [0, 1, 2, 3].map(|i| vec![top[i], bottom[i]]);
+
+        for amphipod in Amphipod::ALL {
+            let count = rooms.iter().flatten().filter(|a| **a == amphipod).count();
+            if count != 2 {
+                let expected =
+                    format!("two amphipods of type {}, found {}", amphipod.to_char(), count);
+                return Err(bottom_line.error_at(1, expected));
+            }
+        }
+
+        Ok(Burrow { rooms })
+    })
+}
+
+/// Checks that `line` is `expected`, ignoring trailing whitespace.
+fn expect_line(line: &Line, expected: &str, what: &str) -> Result<(), ParseError> {
+    if line.text.trim_end() == expected {
+        Ok(())
+    } else {
+        Err(line.error(line.text, what))
+    }
+}
+
+/// Parses a row of rooms following `template`, where '?' marks an amphipod.
+fn parse_row(line: &Line, template: &str) -> Result<[Amphipod; 4], ParseError> {
+    let text = line.text.trim_end();
+
+    if text.chars().count() != template.len() {
+        return Err(line.error(line.text, "a row of four rooms"));
+    }
+
+    let mut row = vec![];
+
+    for (column, (c, t)) in text.chars().zip(template.chars()).enumerate() {
+        if t == '?' {
+            let amphipod = Amphipod::from_char(c)
+                .ok_or_else(|| line.error_at(column + 1, "an amphipod (A, B, C or D)").found(c))?;
+            row.push(amphipod);
+        } else if c != t {
+            return Err(line.error_at(column + 1, format!("{:?}", t)).found(c));
+        }
+    }
+
+    Ok([row[0], row[1], row[2], row[3]])
+}
+
+const HALLWAY: usize = 11;
+/// The deepest rooms the search handles, those of the unfolded burrow.
+const MAX_DEPTH: usize = 4;
+/// Spaces in the hallway amphipods may stop in: all but those just outside a room.
+const STOPS: [usize; 7] = [0, 1, 3, 5, 7, 9, 10];
+
+/// The hallway space just outside a room.
+fn entrance(room: usize) -> usize {
+    2 + 2 * room
+}
+
+/// Where every amphipod is, as searched over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct State {
+    hallway: [Option<Amphipod>; HALLWAY],
+    /// Each room from top to bottom, of which only as many spaces as the burrow is deep are used.
+    rooms: [[Option<Amphipod>; MAX_DEPTH]; 4],
+}
+
+impl State {
+    fn new(burrow: &Burrow) -> Self {
+        let mut rooms = [[None; MAX_DEPTH]; 4];
+
+        for (room, amphipods) in rooms.iter_mut().zip(&burrow.rooms) {
+            for (space, amphipod) in room.iter_mut().zip(amphipods) {
+                *space = Some(*amphipod);
+            }
+        }
+
+        Self { hallway: [None; HALLWAY], rooms }
+    }
+
+    fn is_organized(&self, depth: usize) -> bool {
+        self.rooms.iter()
+            .enumerate()
+            .all(|(i, room)| room[..depth].iter().all(|a| *a == Some(Amphipod::ALL[i])))
+    }
+
+    /// Whether a room holds only amphipods that belong in it, so that others of their type may
+    /// move in and none need to move out.
+    fn is_settled(&self, room: usize, depth: usize) -> bool {
+        self.rooms[room][..depth].iter().flatten().all(|a| a.room() == room)
+    }
+
+    /// Whether the hallway is empty between spaces `from` and `to`, not counting `from` itself.
+    fn is_clear(&self, from: usize, to: usize) -> bool {
+        let between = if from < to { from + 1..=to } else { to..=from - 1 };
+        self.hallway[between].iter().all(Option::is_none)
+    }
+
+    /// The topmost amphipod in a room that needs to move out of it, and its depth in the room.
+    fn leaving(&self, room: usize, depth: usize) -> Option<(Amphipod, usize)> {
+        if self.is_settled(room, depth) {
+            return None;
+        }
+
+        (0..depth).find_map(|i| Some((self.rooms[room][i]?, i)))
+    }
+
+    /// Moves an amphipod standing in hallway space `from` (without being recorded there) into its
+    /// room, if the way is clear and the room is settled. Returns the new state and the number of
+    /// steps taken.
+    fn move_in(&self, amphipod: Amphipod, from: usize, depth: usize) -> Option<(State, usize)> {
+        let room = amphipod.room();
+
+        if !self.is_settled(room, depth) || !self.is_clear(from, entrance(room)) {
+            return None;
+        }
+
+        let space = (0..depth).rev().find(|i| self.rooms[room][*i].is_none())?;
+
+        let mut next = *self;
+        next.rooms[room][space] = Some(amphipod);
+
+        Some((next, from.abs_diff(entrance(room)) + space + 1))
+    }
+
+    /// The states a single move away, along with the energy each move takes. Moving into its own
+    /// room is never a mistake, as every amphipod does so in the end and it can only make way for
+    /// others, so if any amphipod can do so that is the only move considered.
+    fn moves(&self, depth: usize) -> Vec<(State, u32)> {
+        for (from, space) in self.hallway.iter().enumerate() {
+            if let Some(amphipod) = *space {
+                let mut left = *self;
+                left.hallway[from] = None;
+
+                if let Some((next, steps)) = left.move_in(amphipod, from, depth) {
+                    return vec![(next, steps as u32 * amphipod.energy())];
+                }
+            }
+        }
+
+        let leaving = (0..4)
+            .filter_map(|room| {
+                let (amphipod, space) = self.leaving(room, depth)?;
+                let mut left = *self;
+                left.rooms[room][space] = None;
+                Some((room, amphipod, space, left))
+            })
+            .collect::<Vec<(usize, Amphipod, usize, State)>>();
+
+        for (room, amphipod, space, left) in &leaving {
+            if let Some((next, steps)) = left.move_in(*amphipod, entrance(*room), depth) {
+                return vec![(next, (space + 1 + steps) as u32 * amphipod.energy())];
+            }
+        }
+
+        let mut result = vec![];
+
+        for (room, amphipod, space, left) in leaving {
+            for stop in STOPS {
+                if left.is_clear(entrance(room), stop) {
+                    let mut next = left;
+                    next.hallway[stop] = Some(amphipod);
+
+                    let steps = space + 1 + entrance(room).abs_diff(stop);
+                    result.push((next, steps as u32 * amphipod.energy()));
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// The least energy needed to move every amphipod into its own room, found by Dijkstra's algorithm
+/// over the states of the burrow, or `None` if they cannot all get there.
+pub fn least_energy(burrow: &Burrow) -> Option<u32> {
+    let depth = burrow.depth();
+    assert!(
+        depth <= MAX_DEPTH && burrow.rooms.iter().all(|room| room.len() == depth),
+        "rooms must all be of the same depth, at most {}",
+        MAX_DEPTH
+    );
+
+    let start = State::new(burrow);
+
+    let mut least = HashMap::new();
+    least.insert(start, 0);
+
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((0, start)));
+
+    while let Some(Reverse((energy, state))) = queue.pop() {
+        if state.is_organized(depth) {
+            return Some(energy);
+        }
+
+        // check if a better way here has already been found.
+        if energy > least[&state] {
+            continue;
+        }
+
+        for (next, cost) in state.moves(depth) {
+            let next_energy = energy + cost;
+
+            if next_energy < *least.get(&next).unwrap_or(&u32::MAX) {
+                least.insert(next, next_energy);
+                queue.push(Reverse((next_energy, next)));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_parse() {
+        use Amphipod::*;
+
+        let burrow = parse(TEST_INPUT.as_bytes()).unwrap();
+        assert_eq!(
+            [vec![Bronze, Amber], vec![Copper, Desert], vec![Bronze, Copper], vec![Desert, Amber]],
+            burrow.rooms
+        );
+        assert_eq!(TEST_INPUT, burrow.to_string());
+
+        let err = parse(TEST_INPUT.replace("#A#D", "#A#E").as_bytes()).unwrap_err();
+        assert_eq!("4:6: expected an amphipod (A, B, C or D), found \"E\"", err.to_string());
+
+        let err = parse(TEST_INPUT.replace("#A#D", "#A#A").as_bytes()).unwrap_err();
+        assert_eq!("4:1: expected two amphipods of type A, found 3", err.to_string());
+    }
+
+    #[test]
+    fn test_unfolded() {
+        let burrow = parse(TEST_INPUT.as_bytes()).unwrap();
+
+        assert_eq!(
+            "#############\n\
+             #...........#\n\
+             ###B#C#B#D###\n\
+             \x20 #D#C#B#A#\n\
+             \x20 #D#B#A#C#\n\
+             \x20 #A#D#C#A#\n\
+             \x20 #########\n",
+            burrow.unfolded().to_string()
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        use Amphipod::*;
+
+        let burrow = Burrow::new([vec![Amber], vec![Bronze], vec![Copper], vec![Desert]]).unwrap();
+        assert_eq!(3, burrow.unfolded().depth());
+
+        // too deep to be unfolded.
+        let rooms = [vec![Amber; 3], vec![Bronze; 3], vec![Copper; 3], vec![Desert; 3]];
+        let err = Burrow::new(rooms).unwrap_err();
+        assert_eq!("rooms must all be of the same depth, from 1 to 2", err);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let burrow = parse(TEST_INPUT.as_bytes()).unwrap();
+        assert_eq!(12521, Day23::part_1(&burrow).unwrap());
+    }
+
+    #[test]
+    fn test_part_2() {
+        let burrow = parse(TEST_INPUT.as_bytes()).unwrap();
+        assert_eq!(44169, Day23::part_2(&burrow).unwrap());
+    }
+
+    #[test]
+    fn test_cannot_be_organized() {
+        // can be organized, but not once unfolded.
+        let input = TEST_INPUT
+            .replace("###B#C#B#D###", "###A#B#D#D###")
+            .replace("#A#D#C#A#", "#C#A#B#C#");
+        let burrow = parse(input.as_bytes()).unwrap();
+
+        assert_eq!(10614, Day23::part_1(&burrow).unwrap());
+        assert_eq!(
+            Err(SolveError::new("the amphipods cannot be organized")),
+            Day23::part_2(&burrow)
+        );
+    }
+
+    #[test]
+    fn test_organized() {
+        use Amphipod::*;
+
+        let burrow = Burrow { rooms: [vec![Amber], vec![Bronze], vec![Copper], vec![Desert]] };
+        assert_eq!(Some(0), least_energy(&burrow));
+
+        // the bottom amber and bronze amphipods swapped, which first needs the second room emptied.
+        let burrow = Burrow {
+            rooms: [vec![Amber, Bronze], vec![Bronze, Amber], vec![Copper; 2], vec![Desert; 2]],
+        };
+        assert_eq!(Some(112), least_energy(&burrow));
+    }
+}
@@ -0,0 +1,245 @@
+//! The submarine's arithmetic logic unit, and the programs it runs.
+
+use aoc_2021_common::parse::{numbered, parse_lines, Line};
+use aoc_2021_common::ParseError;
+
+use std::fmt;
+use std::io::BufRead;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl Register {
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Operand {
+    Register(Register),
+    Number(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Instruction {
+    /// Reads the next input into the register.
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    /// Divides, rounding towards zero.
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    /// Sets the register to 1 if it equals the operand, or 0 otherwise.
+    Eql(Register, Operand),
+}
+
+/// Why an ALU program could not run to the end, each with the index of the failing instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AluError {
+    MissingInput(usize),
+    DivideByZero(usize),
+    /// A `mod` of a negative number, or by a number that is not positive.
+    InvalidModulo(usize),
+}
+
+impl fmt::Display for AluError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AluError::MissingInput(i) => write!(f, "instruction {}: ran out of input", i + 1),
+            AluError::DivideByZero(i) => write!(f, "instruction {}: divided by zero", i + 1),
+            AluError::InvalidModulo(i) => write!(f, "instruction {}: invalid modulo", i + 1),
+        }
+    }
+}
+
+/// The four registers of the ALU, all starting at zero.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Alu {
+    registers: [i64; 4],
+}
+
+impl Alu {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, register: Register) -> i64 {
+        self.registers[register.index()]
+    }
+
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(register) => self.get(register),
+            Operand::Number(n) => n,
+        }
+    }
+
+    /// Runs `program` from the ALU's current state, taking `inp` instructions' values from
+    /// `input` in order. Arithmetic wraps around on overflow.
+    pub fn run(
+        &mut self,
+        program: &[Instruction],
+        input: impl IntoIterator<Item = i64>,
+    ) -> Result<(), AluError> {
+        let mut input = input.into_iter();
+
+        for (i, instruction) in program.iter().enumerate() {
+            let (register, result) = match *instruction {
+                Instruction::Inp(a) => (a, input.next().ok_or(AluError::MissingInput(i))?),
+                Instruction::Add(a, b) => (a, self.get(a).wrapping_add(self.value(b))),
+                Instruction::Mul(a, b) => (a, self.get(a).wrapping_mul(self.value(b))),
+                Instruction::Div(a, b) => {
+                    let b = self.value(b);
+                    if b == 0 {
+                        return Err(AluError::DivideByZero(i));
+                    }
+                    (a, self.get(a).wrapping_div(b))
+                },
+                Instruction::Mod(a, b) => {
+                    let (a_value, b) = (self.get(a), self.value(b));
+                    if a_value < 0 || b <= 0 {
+                        return Err(AluError::InvalidModulo(i));
+                    }
+                    (a, a_value % b)
+                },
+                Instruction::Eql(a, b) => (a, (self.get(a) == self.value(b)) as i64),
+            };
+
+            self.registers[register.index()] = result;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses an ALU program, one instruction per line, e.g.
+///
+/// ```text
+/// inp w
+/// add z w
+/// mod z 2
+/// ```
+pub fn parse_program(input: impl BufRead) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, |lines| numbered(lines).map(|line| parse_instruction(&line)).collect())
+}
+
+pub(crate) fn parse_instruction(line: &Line) -> Result<Instruction, ParseError> {
+    let mut words = line.text.split_whitespace();
+
+    let op = words.next().ok_or_else(|| line.error_at(1, "an instruction"))?;
+    let binary: Option<fn(Register, Operand) -> Instruction> = match op {
+        "inp" => None,
+        "add" => Some(Instruction::Add),
+        "mul" => Some(Instruction::Mul),
+        "div" => Some(Instruction::Div),
+        "mod" => Some(Instruction::Mod),
+        "eql" => Some(Instruction::Eql),
+        _ => return Err(line.error(op, "an instruction (inp, add, mul, div, mod or eql)")),
+    };
+
+    let a = words.next().ok_or_else(|| line.error_at(line.end(), "a register"))?;
+    let a = parse_register(line, a)?;
+
+    let instruction = match binary {
+        None => Instruction::Inp(a),
+        Some(binary) => {
+            let b = words.next()
+                .ok_or_else(|| line.error_at(line.end(), "a register or a number"))?;
+            let b = match parse_register(line, b) {
+                Ok(register) => Operand::Register(register),
+                Err(_) => Operand::Number(line.parse(b, "a register or a number")?),
+            };
+            binary(a, b)
+        },
+    };
+
+    if let Some(extra) = words.next() {
+        return Err(line.error(extra, "the end of the line"));
+    }
+
+    Ok(instruction)
+}
+
+fn parse_register(line: &Line, text: &str) -> Result<Register, ParseError> {
+    match text {
+        "w" => Ok(Register::W),
+        "x" => Ok(Register::X),
+        "y" => Ok(Register::Y),
+        "z" => Ok(Register::Z),
+        _ => Err(line.error(text, "a register (w, x, y or z)")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the example programs from the puzzle.
+    const NEGATE: &str = "inp x\nmul x -1\n";
+    const THREE_TIMES: &str = "inp z\ninp x\nmul z 3\neql z x\n";
+    const BINARY: &str = "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\nadd x w\n\
+                          mod x 2\ndiv w 2\nmod w 2\n";
+
+    fn run(program: &str, input: &[i64]) -> Result<Alu, AluError> {
+        let program = parse_program(program.as_bytes()).unwrap();
+        let mut alu = Alu::new();
+        alu.run(&program, input.iter().copied())?;
+        Ok(alu)
+    }
+
+    #[test]
+    fn test_run() {
+        assert_eq!(-7, run(NEGATE, &[7]).unwrap().get(Register::X));
+
+        assert_eq!(1, run(THREE_TIMES, &[4, 12]).unwrap().get(Register::Z));
+        assert_eq!(0, run(THREE_TIMES, &[4, 13]).unwrap().get(Register::Z));
+
+        let alu = run(BINARY, &[13]).unwrap();
+        let bits = [Register::W, Register::X, Register::Y, Register::Z].map(|r| alu.get(r));
+        assert_eq!([1, 1, 0, 1], bits);
+
+        // division rounds towards zero.
+        assert_eq!(-2, run("inp x\ndiv x 3\n", &[-7]).unwrap().get(Register::X));
+    }
+
+    #[test]
+    fn test_run_errors() {
+        assert_eq!(Err(AluError::MissingInput(1)), run(THREE_TIMES, &[4]));
+        assert_eq!(Err(AluError::DivideByZero(1)), run("inp x\ndiv x y\n", &[1]));
+        assert_eq!(Err(AluError::InvalidModulo(1)), run("inp x\nmod x 2\n", &[-1]));
+        assert_eq!(Err(AluError::InvalidModulo(1)), run("inp x\nmod x 0\n", &[1]));
+    }
+
+    #[test]
+    fn test_parse_program() {
+        assert_eq!(
+            vec![
+                Instruction::Inp(Register::W),
+                Instruction::Add(Register::Z, Operand::Register(Register::W)),
+                Instruction::Mod(Register::Z, Operand::Number(-2)),
+            ],
+            parse_program("inp w\nadd z w\nmod z -2\n".as_bytes()).unwrap()
+        );
+
+        let err = parse_program("inp w\nsub z w\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            "2:1: expected an instruction (inp, add, mul, div, mod or eql), found \"sub\"",
+            err.to_string()
+        );
+
+        let err = parse_program("inp w\nadd v 1\n".as_bytes()).unwrap_err();
+        assert_eq!("2:5: expected a register (w, x, y or z), found \"v\"", err.to_string());
+
+        let err = parse_program("inp w\nadd z one\n".as_bytes()).unwrap_err();
+        assert_eq!("2:7: expected a register or a number, found \"one\"", err.to_string());
+    }
+}
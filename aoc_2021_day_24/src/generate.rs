@@ -0,0 +1,63 @@
+use crate::BLOCK;
+
+use aoc_2021_common::generate::rng;
+use rand::Rng;
+
+/// Number of digits in a model number.
+pub const DEFAULT_SIZE: usize = 14;
+
+/// A MONAD for model numbers of `size` digits, rounded down to an even number, with its blocks
+/// pushing and popping in a random order and each pair of them allowing a random difference
+/// between their digits.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+
+    let pairs = (size / 2).max(1);
+    let mut pushed = vec![];
+    let mut pushes = 0;
+    let mut result = String::new();
+
+    while pushes < pairs || !pushed.is_empty() {
+        let (divisor, check, offset) = if pushes < pairs && (pushed.is_empty() || rng.gen()) {
+            let offset = rng.gen_range(1..=16);
+            pushed.push(offset);
+            pushes += 1;
+            (1, rng.gen_range(10..=15), offset)
+        } else {
+            let difference = rng.gen_range(-8..=8);
+            (26, difference - pushed.pop().unwrap(), rng.gen_range(1..=16))
+        };
+
+        let params = [divisor, check, offset];
+        let mut params = params.iter();
+        for line in BLOCK {
+            if line.contains('?') {
+                result += &line.replace('?', &params.next().unwrap().to_string());
+            } else {
+                result += line;
+            }
+            result.push('\n');
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day24;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 14), generate(1, 14));
+
+        for seed in 0..20 {
+            let input = Day24::parse(generate(seed, 14).as_bytes()).unwrap();
+            Day24::part_1(&input).unwrap();
+            Day24::part_2(&input).unwrap();
+        }
+    }
+}
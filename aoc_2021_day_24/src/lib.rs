@@ -0,0 +1,300 @@
+pub mod alu;
+pub mod generate;
+
+use crate::alu::{Alu, Instruction, Register};
+
+use aoc_2021_common::parse::{numbered, parse_lines, unexpected_end, Line};
+use aoc_2021_common::{ParseError, Solution, SolveError};
+
+use std::io::BufRead;
+
+/// Day 24: Arithmetic Logic Unit.
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Monad;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        model_number(input, true).ok_or_else(no_model_number)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        model_number(input, false).ok_or_else(no_model_number)
+    }
+}
+
+fn no_model_number() -> SolveError {
+    SolveError::new("MONAD accepts no model number")
+}
+
+/// The instructions MONAD repeats for each digit of the model number, with '?' where the blocks
+/// differ.
+pub(crate) const BLOCK: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z ?", "add x ?", "eql x w", "eql x 0",
+    "mul y 0", "add y 25", "mul y x", "add y 1", "mul z y", "mul y 0", "add y w", "add y ?",
+    "mul y x", "add z y",
+];
+
+/// What sets one of MONAD's blocks apart from the others. Each block reads a digit `w` and does
+/// `x = z % 26 + check; z /= (26 if pops else 1); if x != w { z = z * 26 + w + offset }`, so that
+/// `z` works as a stack of base 26 digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Block {
+    pub pops: bool,
+    pub check: i64,
+    pub offset: i64,
+}
+
+/// The model number checking program, and the blocks it is made of.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Monad {
+    pub program: Vec<Instruction>,
+    pub blocks: Vec<Block>,
+    /// Each pushing block paired with the later block popping its digit, by index.
+    pub pairs: Vec<(usize, usize)>,
+}
+
+impl Monad {
+    /// Whether MONAD accepts a model number, given as its digits, by running it on the ALU.
+    pub fn accepts(&self, digits: &[i64]) -> bool {
+        let mut alu = Alu::new();
+        alu.run(&self.program, digits.iter().copied()).is_ok() && alu.get(Register::Z) == 0
+    }
+}
+
+/// Parses MONAD, which must be made of blocks following `BLOCK`. Each block that doesn't pop must
+/// push, and pair up with a later block popping its digit, with the difference between their
+/// digits that MONAD requires being at most 8 either way. The pairs are kept for the solver.
+pub fn parse(input: impl BufRead) -> Result<Monad, ParseError> {
+    parse_lines(input, |lines| {
+        let program = numbered(lines)
+            .map(|line| alu::parse_instruction(&line))
+            .collect::<Result<Vec<Instruction>, ParseError>>()?;
+
+        if lines.is_empty() {
+            return Err(unexpected_end(lines, format!("{:?}", BLOCK[0])));
+        }
+
+        let mut blocks: Vec<Block> = vec![];
+        let mut pairs = vec![];
+        // each block whose digit is still to be popped, and the line of its offset.
+        let mut pushed = vec![];
+        let mut lines_iter = numbered(lines).peekable();
+
+        while lines_iter.peek().is_some() {
+            let mut params = vec![];
+
+            for template in BLOCK {
+                let line = lines_iter.next()
+                    .ok_or_else(|| unexpected_end(lines, format!("{:?}", template)))?;
+                params.extend(parse_block_line(&line, template)?);
+            }
+
+            let pops = match params[0] {
+                (_, 1) => false,
+                (_, 26) => true,
+                (line, _) => return Err(line.error(line.text, "\"div z 1\" or \"div z 26\"")),
+            };
+
+            let (check_line, check) = params[1];
+            let (offset_line, offset) = params[2];
+
+            if !pops {
+                if check < 10 {
+                    return Err(check_line.error(check_line.text, "a check of at least 10"));
+                }
+                pushed.push((blocks.len(), offset_line));
+            } else {
+                let (push_block, push_line) = pushed.pop().ok_or_else(|| {
+                    params[0].0.error(params[0].0.text, "\"div z 1\", as no digit is left to pop")
+                })?;
+                let push_offset = blocks[push_block].offset;
+
+                let difference = push_offset.checked_add(check);
+                if !difference.is_some_and(|d| (-8..=8).contains(&d)) {
+                    let expected = format!(
+                        "a check from {} to {}, to pair with the offset on line {}",
+                        (-8i64).saturating_sub(push_offset),
+                        8i64.saturating_sub(push_offset),
+                        push_line.number
+                    );
+                    return Err(check_line.error(check_line.text, expected));
+                }
+
+                pairs.push((push_block, blocks.len()));
+            }
+
+            blocks.push(Block { pops, check, offset });
+        }
+
+        if !pushed.is_empty() {
+            let expected = format!("{} more block(s) popping the digits pushed", pushed.len());
+            return Err(unexpected_end(lines, expected));
+        }
+
+        Ok(Monad { program, blocks, pairs })
+    })
+}
+
+/// Checks a line of a block against its template, returning the number in place of a '?'.
+fn parse_block_line<'a>(
+    line: &Line<'a>,
+    template: &str,
+) -> Result<Option<(Line<'a>, i64)>, ParseError> {
+    let words = line.text.split_whitespace().collect::<Vec<&str>>();
+    let expected = template.split(' ').collect::<Vec<&str>>();
+
+    if words.len() != expected.len() {
+        return Err(line.error(line.text, format!("{:?}", template)));
+    }
+
+    let mut param = None;
+
+    for (word, expected) in words.into_iter().zip(expected) {
+        if expected == "?" {
+            param = Some((*line, line.parse(word, "a number")?));
+        } else if word != expected {
+            return Err(line.error(word, format!("{:?}", template)));
+        }
+    }
+
+    Ok(param)
+}
+
+/// The largest or smallest model number MONAD accepts, if any.
+///
+/// Every block that doesn't pop must push, as `check` is always above 9 for them, so each pushing
+/// block pairs up with the popping block that pops its digit. Those blocks' digits must then
+/// differ by the pushing block's `offset` plus the popping block's `check`, so that the popping
+/// block doesn't push again and `z` ends up empty. The number found is checked by running MONAD on
+/// it, in case the blocks don't work out that way.
+pub fn model_number(monad: &Monad, largest: bool) -> Option<u64> {
+    let mut digits = vec![0; monad.blocks.len()];
+
+    for &(i, j) in &monad.pairs {
+        let difference = monad.blocks.get(i)?.offset.checked_add(monad.blocks.get(j)?.check)?;
+        if difference.abs() > 8 {
+            return None;
+        }
+
+        digits[i] = if largest { 9.min(9 - difference) } else { 1.max(1 - difference) };
+        digits[j] = digits[i] + difference;
+    }
+
+    if !digits.iter().all(|digit| (1..=9).contains(digit)) || !monad.accepts(&digits) {
+        return None;
+    }
+
+    Some(digits.iter().fold(0, |n, digit| n * 10 + *digit as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+
+    // a hand-written MONAD for four digit model numbers.
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    fn digits(n: u64) -> Vec<i64> {
+        n.to_string().bytes().map(|b| (b - b'0') as i64).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let monad = parse(TEST_INPUT.as_bytes()).unwrap();
+        assert_eq!(72, monad.program.len());
+        assert_eq!(
+            vec![
+                Block { pops: false, check: 12, offset: 4 },
+                Block { pops: false, check: 11, offset: 7 },
+                Block { pops: true, check: -9, offset: 3 },
+                Block { pops: true, check: -2, offset: 5 },
+            ],
+            monad.blocks
+        );
+
+        let err = parse(TEST_INPUT.replacen("div z 1", "div z 2", 1).as_bytes()).unwrap_err();
+        assert_eq!("5:1: expected \"div z 1\" or \"div z 26\", found \"div z 2\"", err.to_string());
+
+        let err = parse(TEST_INPUT.replacen("mul x 0", "mul x 1", 1).as_bytes()).unwrap_err();
+        assert_eq!("2:7: expected \"mul x 0\", found \"1\"", err.to_string());
+
+        let truncated = TEST_INPUT.lines().take(20).collect::<Vec<&str>>().join("\n");
+        let err = parse(truncated.as_bytes()).unwrap_err();
+        assert_eq!("20:8: expected \"add x z\"", err.to_string());
+
+        let block = TEST_INPUT.lines().take(18).collect::<Vec<&str>>().join("\n");
+        let err = parse(block.as_bytes()).unwrap_err();
+        assert_eq!("18:8: expected 1 more block(s) popping the digits pushed", err.to_string());
+
+        let err = parse(TEST_INPUT.replacen("div z 1", "div z 26", 1).as_bytes()).unwrap_err();
+        assert_eq!(
+            "5:1: expected \"div z 1\", as no digit is left to pop, found \"div z 26\"",
+            err.to_string()
+        );
+
+        let err = parse(TEST_INPUT.replacen("add x 12", "add x 9", 1).as_bytes()).unwrap_err();
+        assert_eq!("6:1: expected a check of at least 10, found \"add x 9\"", err.to_string());
+
+        // the second block's digit is popped by the third, which would need them 13 apart.
+        let err = parse(TEST_INPUT.replacen("add x -9", "add x 6", 1).as_bytes()).unwrap_err();
+        assert_eq!(
+            "42:1: expected a check from -15 to 1, to pair with the offset on line 34, found \"add x \
+             6\"",
+            err.to_string()
+        );
+
+        // an offset so far out that adding the check to it overflows.
+        let input = TEST_INPUT.replacen("add y 4", "add y -9223372036854775808", 1);
+        let err = parse(input.as_bytes()).unwrap_err();
+        assert_eq!(
+            "60:1: expected a check from 9223372036854775800 to 9223372036854775807, to pair \
+             with the offset on line 16, found \"add x -2\"",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        let monad = parse(TEST_INPUT.as_bytes()).unwrap();
+        assert_eq!(7979, Day24::part_1(&monad).unwrap());
+    }
+
+    #[test]
+    fn test_part_2() {
+        let monad = parse(TEST_INPUT.as_bytes()).unwrap();
+        assert_eq!(1313, Day24::part_2(&monad).unwrap());
+    }
+
+    #[test]
+    fn test_model_number() {
+        let monad = parse(TEST_INPUT.as_bytes()).unwrap();
+
+        // every four digit model number, without zeros.
+        let accepted = (1111..=9999)
+            .filter(|n| !n.to_string().contains('0') && monad.accepts(&digits(*n)))
+            .collect::<Vec<u64>>();
+
+        assert_eq!(accepted.last().copied(), model_number(&monad, true));
+        assert_eq!(accepted.first().copied(), model_number(&monad, false));
+
+        for seed in 0..10 {
+            let monad = parse(generate(seed, 14).as_bytes()).unwrap();
+            for largest in [true, false] {
+                let n = model_number(&monad, largest).unwrap();
+                assert!(monad.accepts(&digits(n)), "seed {}: {}", seed, n);
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_2021_common::main::<aoc_2021_day_24::Day24>();
+}
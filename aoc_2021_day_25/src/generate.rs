@@ -0,0 +1,48 @@
+use crate::parse;
+
+use aoc_2021_common::generate::{char_grid, rng};
+use rand::Rng;
+
+/// Width and height of the sea floor in the real puzzle input, near enough.
+pub const DEFAULT_SIZE: usize = 139;
+
+/// A `size` by `size` sea floor, crowded enough that the herds soon block each other.
+///
+/// Herds that never stop, which the real inputs rule out, are most likely on small sea floors, so
+/// those that are still moving after a while are thrown away and another is tried.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = rng(seed);
+    let size = size.max(1);
+
+    loop {
+        let input = char_grid(size, size, || match rng.gen_range(0..10) {
+            0..=3 => '>',
+            4..=7 => 'v',
+            _ => '.',
+        });
+
+        let mut floor = parse(input.as_bytes()).unwrap();
+        if (0..size * 10).any(|_| !floor.step()) {
+            return input;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day25;
+
+    use aoc_2021_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+
+        for seed in 0..20 {
+            let input = Day25::parse(generate(seed, 20).as_bytes()).unwrap();
+            Day25::part_1(&input).unwrap();
+            Day25::part_2(&input).unwrap();
+        }
+    }
+}
@@ -0,0 +1,227 @@
+pub mod generate;
+
+use aoc_2021_common::parse::{numbered, parse_lines};
+use aoc_2021_common::{Grid, Param, Params, ParseError, Solution, SolveError};
+use tracing::info;
+
+use std::fmt;
+use std::io::BufRead;
+
+/// Day 25: Sea Cucumber.
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "show_step",
+        default: 0,
+        min: 0,
+        max: 1_000_000,
+        about: "step after which part one logs the sea floor (see --trace), or 0 for none",
+    }];
+
+    type Input = SeaFloor;
+    type Answer1 = usize;
+    type Answer2 = &'static str;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Self::part_1_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part_1_with(input: &Self::Input, params: &Params) -> Result<Self::Answer1, SolveError> {
+        let show_step = params.try_get("show_step")?;
+        if show_step > 0 {
+            info!("sea floor after step {}:\n{}", show_step, input.after(show_step));
+        }
+
+        first_still_step(input)
+            .ok_or_else(|| SolveError::new("the sea cucumbers never stop moving"))
+    }
+
+    /// There is no puzzle for part two, just the last star for having all the others.
+    fn part_2(_input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok("Remotely Start The Sleigh")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Cell {
+    Empty,
+    East,
+    South,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            Cell::Empty => '.',
+            Cell::East => '>',
+            Cell::South => 'v',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+/// The sea floor, wrapping around at its edges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeaFloor {
+    pub grid: Grid<Cell>,
+}
+
+impl SeaFloor {
+    /// Moves the east-facing herd and then the south-facing herd, returning whether any sea
+    /// cucumber moved.
+    pub fn step(&mut self) -> bool {
+        let east = self.move_herd(Cell::East, (0, 1));
+        let south = self.move_herd(Cell::South, (1, 0));
+
+        east || south
+    }
+
+    /// Moves every sea cucumber of a herd with an empty space ahead of it at the same time.
+    fn move_herd(&mut self, herd: Cell, (d_row, d_col): (usize, usize)) -> bool {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+
+        let moving = self.grid.iter()
+            .filter(|(_, cell)| **cell == herd)
+            .filter_map(|((row, col), _)| {
+                let ahead = ((row + d_row) % rows, (col + d_col) % cols);
+                Some(((row, col), ahead)).filter(|_| self.grid[ahead] == Cell::Empty)
+            })
+            .collect::<Vec<((usize, usize), (usize, usize))>>();
+
+        for (from, to) in &moving {
+            self.grid[*from] = Cell::Empty;
+            self.grid[*to] = herd;
+        }
+
+        !moving.is_empty()
+    }
+
+    /// The sea floor after `steps` steps, e.g. for printing.
+    pub fn after(&self, steps: usize) -> Self {
+        let mut floor = self.clone();
+
+        for _ in 0..steps {
+            floor.step();
+        }
+
+        floor
+    }
+}
+
+/// Draws the sea floor as in the puzzle input.
+impl fmt::Display for SeaFloor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+/// Parses the sea floor, one row of '>', 'v' and '.' per line.
+pub fn parse(input: impl BufRead) -> Result<SeaFloor, ParseError> {
+    parse_lines(input, |lines| {
+        let grid = Grid::parse_chars(numbered(lines), "'>', 'v' or '.'", |c| match c {
+            '.' => Some(Cell::Empty),
+            '>' => Some(Cell::East),
+            'v' => Some(Cell::South),
+            _ => None,
+        })?;
+
+        Ok(SeaFloor { grid })
+    })
+}
+
+/// The first step on which no sea cucumber moves, or `None` if the herds never stop, as the sea
+/// floor comes back round to how it was after an earlier step.
+pub fn first_still_step(floor: &SeaFloor) -> Option<usize> {
+    let mut floor = floor.clone();
+    // rather than keeping every sea floor seen, one is kept at steps that are powers of two, which
+    // any cycle comes back round to soon after it is longer than that step (Brent's algorithm).
+    let mut kept = floor.clone();
+    let mut keep_step = 1;
+    let mut step = 1;
+
+    while floor.step() {
+        if floor == kept {
+            return None;
+        }
+        if step == keep_step {
+            kept = floor.clone();
+            keep_step *= 2;
+        }
+        step += 1;
+    }
+
+    Some(step)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    fn floor(text: &str) -> SeaFloor {
+        parse(text.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_step() {
+        let row = floor("...>>>>>...");
+        assert_eq!("...>>>>.>..", row.after(1).to_string());
+        assert_eq!("...>>>.>.>.", row.after(2).to_string());
+
+        // the east-facing herd moves first, and both wrap around.
+        let wrapping = floor("...>...\n.......\n......>\nv.....>\n......>\n.......\n..vvv..");
+        assert_eq!(
+            ">......\n..v....\n..>.v..\n.>.v...\n...>...\n.......\nv......",
+            wrapping.after(4).to_string()
+        );
+
+        let example = floor(TEST_INPUT);
+        assert_eq!(
+            "....>.>v.>\nv.v>.>v.v.\n>v>>..>v..\n>>v>v>.>.v\n.>v.v...v.\n\
+             v>>.>vvv..\n..v...>>..\nvv...>>vv.\n>.v.v..v.v",
+            example.after(1).to_string()
+        );
+        assert_eq!(
+            "..>>v>vv..\n..v.>>vv..\n..>>v>>vv.\n..>>>>>vv.\nv......>vv\n\
+             v>v....>>v\nvvv.....>>\n>vv......>\n.>v.vv.v..",
+            example.after(58).to_string()
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        let floor = parse(TEST_INPUT.as_bytes()).unwrap();
+        assert_eq!(58, Day25::part_1(&floor).unwrap());
+    }
+
+    #[test]
+    fn test_never_still() {
+        for text in [">.", "v\n.", ">..\n.v.\n..."] {
+            let floor = floor(text);
+            assert_eq!(None, first_still_step(&floor), "{}", text);
+            assert_eq!(
+                Err(SolveError::new("the sea cucumbers never stop moving")),
+                Day25::part_1(&floor)
+            );
+        }
+    }
+
+    #[test]
+    fn test_params() {
+        let floor = floor(TEST_INPUT);
+        let params = Params::parse(Day25::PARAMS, &["show_step=10".to_string()]).unwrap();
+
+        // showing the sea floor along the way leaves the answer alone.
+        assert_eq!(58, Day25::part_1_with(&floor, &params).unwrap());
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_2021_common::Solution;
+use aoc_2021_day_25::Day25;
+
+use std::env;
+use std::path::Path;
+use std::process;
+
+/// Solves the puzzle like every other day, or with `--step <steps> [input]` prints the sea floor
+/// after that many steps instead.
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    if args.first().map(String::as_str) != Some("--step") {
+        aoc_2021_common::main::<Day25>();
+        return;
+    }
+
+    let steps = match args.get(1).and_then(|s| s.parse::<usize>().ok()) {
+        Some(steps) => steps,
+        None => {
+            eprintln!("error: --step needs a number of steps");
+            process::exit(1);
+        },
+    };
+    let path = args.get(2).map_or("input.txt", String::as_str);
+
+    match Day25::parse_path(Path::new(path)) {
+        Ok(floor) => println!("{}", floor.after(steps)),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        },
+    }
+}
@@ -0,0 +1,66 @@
+//! Benchmarks parsing and each part's solver separately, on both the example and the real input of
+//! every day, e.g. `cargo bench -p aoc_2021_runner -- day_19/real`.
+
+use aoc_2021_common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use std::hint::black_box;
+
+/// Reads a file from the given day's crate at compile time.
+macro_rules! input {
+    ($day:literal, $file:literal) => {
+        include_str!(concat!("../../aoc_2021_day_", $day, "/", $file))
+    };
+}
+
+/// Benchmarks `S` on the example and real inputs. Slow days take fewer samples.
+fn bench_day<S: Solution>(c: &mut Criterion, example: &str, real: &str, samples: usize) {
+    for (name, text) in [("example", example), ("real", real)] {
+        let mut group = c.benchmark_group(format!("day_{:02}/{}", S::DAY, name));
+        group.sample_size(samples);
+
+        group.bench_function("parse", |b| {
+            b.iter(|| S::parse(black_box(text.as_bytes())).unwrap())
+        });
+
+        let input = S::parse(text.as_bytes()).unwrap();
+
+        group.bench_function("part_1", |b| b.iter(|| S::part_1(black_box(&input))));
+        group.bench_function("part_2", |b| b.iter(|| S::part_2(black_box(&input))));
+
+        group.finish();
+    }
+}
+
+fn solutions(c: &mut Criterion) {
+    bench_day::<aoc_2021_day_01::Day01>(c, input!("01", "test_input.txt"), input!("01", "input.txt"), 100);
+    bench_day::<aoc_2021_day_02::Day02>(c, input!("02", "test_input.txt"), input!("02", "input.txt"), 100);
+    bench_day::<aoc_2021_day_03::Day03>(c, input!("03", "test_input.txt"), input!("03", "input.txt"), 100);
+    bench_day::<aoc_2021_day_04::Day04>(c, input!("04", "test_input.txt"), input!("04", "input.txt"), 100);
+    bench_day::<aoc_2021_day_05::Day05>(c, input!("05", "test_input.txt"), input!("05", "input.txt"), 100);
+    bench_day::<aoc_2021_day_06::Day06>(c, input!("06", "test_input.txt"), input!("06", "input.txt"), 100);
+    bench_day::<aoc_2021_day_07::Day07>(c, input!("07", "test_input.txt"), input!("07", "input.txt"), 100);
+    bench_day::<aoc_2021_day_08::Day08>(c, input!("08", "test_input.txt"), input!("08", "input.txt"), 100);
+    bench_day::<aoc_2021_day_09::Day09>(c, input!("09", "test_input.txt"), input!("09", "input.txt"), 100);
+    bench_day::<aoc_2021_day_10::Day10>(c, input!("10", "test_input.txt"), input!("10", "input.txt"), 100);
+    bench_day::<aoc_2021_day_11::Day11>(c, input!("11", "test_input.txt"), input!("11", "input.txt"), 100);
+    bench_day::<aoc_2021_day_12::Day12>(c, input!("12", "test_input_1.txt"), input!("12", "input.txt"), 100);
+    bench_day::<aoc_2021_day_13::Day13>(c, input!("13", "test_input.txt"), input!("13", "input.txt"), 100);
+    bench_day::<aoc_2021_day_14::Day14>(c, input!("14", "test_input.txt"), input!("14", "input.txt"), 100);
+    bench_day::<aoc_2021_day_15::Day15>(c, input!("15", "test_input.txt"), input!("15", "input.txt"), 10);
+    // day 16's examples are single transmissions kept in its tests.
+    bench_day::<aoc_2021_day_16::Day16>(c, "9C0141080250320F1802104A08", input!("16", "input.txt"), 100);
+    bench_day::<aoc_2021_day_17::Day17>(c, input!("17", "test_input.txt"), input!("17", "input.txt"), 100);
+    bench_day::<aoc_2021_day_18::Day18>(c, input!("18", "test_input.txt"), input!("18", "input.txt"), 10);
+    // locating every scanner, done once while parsing, is where nearly all of day 19's time goes.
+    bench_day::<aoc_2021_day_19::Day19>(c, input!("19", "test_input.txt"), input!("19", "input.txt"), 10);
+    bench_day::<aoc_2021_day_20::Day20>(c, input!("20", "test_input.txt"), input!("20", "input.txt"), 10);
+    bench_day::<aoc_2021_day_21::Day21>(c, input!("21", "test_input.txt"), input!("21", "input.txt"), 10);
+    bench_day::<aoc_2021_day_22::Day22>(c, input!("22", "test_input_2.txt"), input!("22", "input.txt"), 10);
+    bench_day::<aoc_2021_day_23::Day23>(c, input!("23", "test_input.txt"), input!("23", "input.txt"), 10);
+    bench_day::<aoc_2021_day_24::Day24>(c, input!("24", "test_input.txt"), input!("24", "input.txt"), 100);
+    bench_day::<aoc_2021_day_25::Day25>(c, input!("25", "test_input.txt"), input!("25", "input.txt"), 10);
+}
+
+criterion_group!(benches, solutions);
+criterion_main!(benches);
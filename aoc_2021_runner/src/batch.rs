@@ -0,0 +1,102 @@
+//! Solving the inputs of several users, laid out as `inputs/<user>/day_NN.txt`.
+
+use std::fs;
+use std::io;
+use std::iter;
+use std::path::{Path, PathBuf};
+
+/// The `inputs` directory at the root of the workspace.
+pub fn default_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("inputs")
+}
+
+/// The path of a user's input for a day, whether or not it exists.
+pub fn input_path(dir: &Path, user: &str, day: u8) -> PathBuf {
+    dir.join(user).join(format!("day_{:02}.txt", day))
+}
+
+/// The users with a directory of inputs in `dir`, sorted by name.
+pub fn users(dir: &Path) -> io::Result<Vec<String>> {
+    let mut users = vec![];
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            users.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+
+    users.sort();
+    Ok(users)
+}
+
+/// Lays out `rows` of cells under `header`, with each column as wide as its widest line. Cells
+/// spanning several lines (e.g. rendered letters) are printed side by side, line by line.
+pub fn table(header: &[String], rows: &[Vec<String>]) -> String {
+    let all_rows = || iter::once(header).chain(rows.iter().map(Vec::as_slice));
+
+    let mut widths = vec![0; header.len()];
+    for row in all_rows() {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = cell.lines().map(|l| l.chars().count()).fold(*width, usize::max);
+        }
+    }
+
+    let mut result = String::new();
+
+    for row in all_rows() {
+        let height = row.iter().map(|cell| cell.lines().count()).max().unwrap_or(0).max(1);
+
+        for i in 0..height {
+            let line = row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<1$}", cell.lines().nth(i).unwrap_or(""), width))
+                .collect::<Vec<String>>()
+                .join("  ");
+
+            result += line.trim_end();
+            result.push('\n');
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env;
+    use std::process;
+
+    #[test]
+    fn test_users() {
+        let dir = env::temp_dir().join(format!("aoc2021_batch_{}", process::id()));
+        for user in ["bob", "alice"] {
+            fs::create_dir_all(dir.join(user)).unwrap();
+        }
+        fs::write(dir.join("README"), "not a user").unwrap();
+
+        let users = users(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(vec!["alice", "bob"], users.unwrap());
+        assert_eq!(dir.join("bob").join("day_07.txt"), input_path(&dir, "bob", 7));
+    }
+
+    #[test]
+    fn test_table() {
+        let cells = |cells: &[&str]| cells.iter().map(|c| c.to_string()).collect::<Vec<String>>();
+
+        let header = cells(&["", "alice", "bob"]);
+        let rows = [cells(&["Part 1", "1624", "-"]), cells(&["Part 2", "#..#\n####", "11"])];
+
+        assert_eq!(
+            "        alice  bob\n\
+             Part 1  1624   -\n\
+             Part 2  #..#   11\n\
+             \x20       ####\n",
+            table(&header, &rows)
+        );
+    }
+}
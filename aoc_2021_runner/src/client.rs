@@ -0,0 +1,166 @@
+//! A client for the parts of adventofcode.com the runner uses, logged in with a session cookie.
+
+use crate::config::Config;
+use crate::submit::{self, Verdict};
+
+use aoc_2021_common::Part;
+
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+const YEAR: u16 = 2021;
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(concat!("aoc_2021_runner/", env!("CARGO_PKG_VERSION")))
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Self {
+        Self::new(&config.base_url, &config.session)
+    }
+
+    /// Downloads the puzzle input for a day.
+    pub fn input(&self, day: u8) -> Result<String, String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+
+        let response = self.agent.get(&url)
+            .set("Cookie", &self.cookie())
+            .call()
+            .map_err(|e| request_error(day, e))?;
+
+        response.into_string().map_err(|e| format!("fetching day {}: {}", day, e))
+    }
+
+    /// Submits an answer to a part of a day, returning what was made of it and how long a wrong
+    /// answer locks out the next one, in seconds.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<(Verdict, u64), String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, YEAR, day);
+
+        let response = self.agent.post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.number().to_string()), ("answer", answer)])
+            .map_err(|e| request_error(day, e))?;
+
+        let page = response.into_string().map_err(|e| format!("submitting day {}: {}", day, e))?;
+
+        Ok((submit::parse_verdict(&page)?, submit::lockout_seconds(&page)))
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn request_error(day: u8, err: ureq::Error) -> String {
+    match err {
+        // what adventofcode.com answers when not logged in, or asked about a day too early.
+        ureq::Error::Status(400, _) => "the session token was rejected".to_string(),
+        ureq::Error::Status(404, _) => format!("day {} is not available yet", day),
+        err => format!("day {}: {}", day, err),
+    }
+}
+
+/// Downloads the input for a day into `path`, unless it is already there. Returns whether it was
+/// downloaded.
+pub fn fetch_into(client: &Client, day: u8, path: &Path) -> Result<bool, String> {
+    if path.exists() {
+        return Ok(false);
+    }
+
+    let input = client.input(day)?;
+
+    // write it in full before it can be mistaken for a cached input.
+    let partial = path.with_extension("part");
+    let write = || {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&partial, &input)?;
+        fs::rename(&partial, path)
+    };
+    write().map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    use std::env;
+    use std::process;
+
+    #[test]
+    fn test_fetch_into() {
+        let server = MockServer::start(&[(200, "199\n200\n"), (200, "unexpected second fetch")]);
+        let client = Client::new(server.url(), "abc123");
+
+        let dir = env::temp_dir().join(format!("aoc2021_fetch_{}", process::id()));
+        let path = dir.join("alice").join("day_01.txt");
+
+        let first = fetch_into(&client, 1, &path);
+        let second = fetch_into(&client, 1, &path);
+        let input = fs::read_to_string(&path);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(Ok(true), first);
+        assert_eq!(Ok(false), second);
+        assert_eq!("199\n200\n", input.unwrap());
+
+        let received = server.received();
+        assert_eq!(1, received.len());
+        assert_eq!("GET", received[0].method);
+        assert_eq!("/2021/day/1/input", received[0].path);
+        assert_eq!(Some("session=abc123"), received[0].cookie.as_deref());
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let server = MockServer::start(&[(400, "Please log in"), (404, "Not Found")]);
+        let client = Client::new(server.url(), "expired");
+
+        let path = env::temp_dir().join(format!("aoc2021_fetch_error_{}.txt", process::id()));
+
+        assert_eq!(Err("the session token was rejected".to_string()), fetch_into(&client, 1, &path));
+        assert_eq!(Err("day 25 is not available yet".to_string()), fetch_into(&client, 25, &path));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = MockServer::start(&[
+            (200, "<p>That's not the right answer; your answer is too low.  Please wait one minute \
+                before trying again.</p>"),
+            (200, "<article><p>That's the right answer!</p></article>"),
+            (200, "<article><p>Under maintenance</p></article>"),
+        ]);
+        let client = Client::new(server.url(), "abc123");
+
+        assert_eq!(Ok((Verdict::TooLow, 60)), client.submit(1, Part::Two, "1600"));
+        assert_eq!(Ok((Verdict::Correct, 0)), client.submit(1, Part::Two, "1653"));
+        assert!(client.submit(1, Part::Two, "1653").is_err());
+
+        let received = server.received();
+        assert_eq!("POST", received[0].method);
+        assert_eq!("/2021/day/1/answer", received[0].path);
+        assert_eq!(Some("session=abc123"), received[0].cookie.as_deref());
+        assert_eq!("level=2&answer=1600", received[0].body);
+    }
+}
@@ -0,0 +1,54 @@
+//! Settings for talking to adventofcode.com, read from `aoc2021.toml`.
+
+use serde::Deserialize;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The `session` cookie of a logged in adventofcode.com account.
+    pub session: String,
+    /// Whose inputs these are, naming the directory they are cached in: inputs/<user>.
+    pub user: String,
+    /// Where to reach Advent of Code, e.g. a local stand-in server.
+    #[serde(default = "default_base_url")]
+    pub base_url: String,
+}
+
+fn default_base_url() -> String {
+    "https://adventofcode.com".to_string()
+}
+
+/// The `aoc2021.toml` at the root of the workspace.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("aoc2021.toml")
+}
+
+pub fn load(path: &Path) -> Result<Config, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    toml::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config: Config = toml::from_str("session = \"abc123\"\nuser = \"alice\"\n").unwrap();
+        assert_eq!(
+            Config {
+                session: "abc123".to_string(),
+                user: "alice".to_string(),
+                base_url: "https://adventofcode.com".to_string(),
+            },
+            config
+        );
+
+        assert!(toml::from_str::<Config>("user = \"alice\"\n").is_err());
+        assert!(toml::from_str::<Config>("session = \"a\"\nuser = \"b\"\ntoken = \"c\"\n").is_err());
+    }
+}
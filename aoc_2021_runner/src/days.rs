@@ -0,0 +1,207 @@
+use aoc_2021_common::{run, Param, ParseError, Runner, Solution};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+
+pub struct Day {
+    pub number: u8,
+    pub run: Runner,
+    /// Constants the day's solution declares, which `run` can be given other values for.
+    pub params: &'static [Param],
+    /// Builds a random input from a seed and a size, see each day's `generate` module.
+    pub generate: fn(u64, usize) -> String,
+    /// The size of the real puzzle input, in whatever units the day's generator uses.
+    pub default_size: usize,
+    /// Parses an input into the model the day's parts are solved from, as pretty-printed JSON.
+    pub model: fn(&mut dyn BufRead) -> Result<String, ParseError>,
+}
+
+impl Day {
+    /// The `input.txt` shipped in the day's crate.
+    pub fn default_input(&self) -> PathBuf {
+        self.crate_dir().join("input.txt")
+    }
+
+    /// The `answers.txt` shipped in the day's crate, recording the answers to `input.txt`.
+    pub fn answers(&self) -> PathBuf {
+        self.crate_dir().join("answers.txt")
+    }
+
+    fn crate_dir(&self) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(format!("aoc_2021_day_{:02}", self.number))
+    }
+}
+
+/// Parses an input and serializes the model, requiring it to be `Deserialize` as well so that every
+/// dump can be read back in.
+fn model<S: Solution>(input: &mut dyn BufRead) -> Result<String, ParseError>
+where
+    S::Input: Serialize + DeserializeOwned,
+{
+    let model = S::parse(input)?;
+
+    Ok(serde_json::to_string_pretty(&model).expect("models only have string or number keys"))
+}
+
+/// The entry for a day's crate, e.g. `day!(1, aoc_2021_day_01, Day01)`.
+macro_rules! day {
+    ($number:literal, $krate:ident, $solution:ident) => {
+        Day {
+            number: $number,
+            run: run::<$krate::$solution>,
+            params: <$krate::$solution as Solution>::PARAMS,
+            generate: $krate::generate::generate,
+            default_size: $krate::generate::DEFAULT_SIZE,
+            model: model::<$krate::$solution>,
+        }
+    };
+}
+
+/// Every implemented day, in order.
+pub const DAYS: &[Day] = &[
+    day!(1, aoc_2021_day_01, Day01),
+    day!(2, aoc_2021_day_02, Day02),
+    day!(3, aoc_2021_day_03, Day03),
+    day!(4, aoc_2021_day_04, Day04),
+    day!(5, aoc_2021_day_05, Day05),
+    day!(6, aoc_2021_day_06, Day06),
+    day!(7, aoc_2021_day_07, Day07),
+    day!(8, aoc_2021_day_08, Day08),
+    day!(9, aoc_2021_day_09, Day09),
+    day!(10, aoc_2021_day_10, Day10),
+    day!(11, aoc_2021_day_11, Day11),
+    day!(12, aoc_2021_day_12, Day12),
+    day!(13, aoc_2021_day_13, Day13),
+    day!(14, aoc_2021_day_14, Day14),
+    day!(15, aoc_2021_day_15, Day15),
+    day!(16, aoc_2021_day_16, Day16),
+    day!(17, aoc_2021_day_17, Day17),
+    day!(18, aoc_2021_day_18, Day18),
+    day!(19, aoc_2021_day_19, Day19),
+    day!(20, aoc_2021_day_20, Day20),
+    day!(21, aoc_2021_day_21, Day21),
+    day!(22, aoc_2021_day_22, Day22),
+    day!(23, aoc_2021_day_23, Day23),
+    day!(24, aoc_2021_day_24, Day24),
+    day!(25, aoc_2021_day_25, Day25),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Solves part one from the model read back in from its dump, as well as from the input.
+    fn assert_reloads<S: Solution>(size: usize)
+    where
+        S::Input: Serialize + DeserializeOwned,
+    {
+        let day = find(S::DAY).unwrap();
+        let input = (day.generate)(0, size);
+
+        let json = (day.model)(&mut input.as_bytes()).unwrap();
+        let reloaded: S::Input = serde_json::from_str(&json).unwrap();
+        let parsed = S::parse(input.as_bytes()).unwrap();
+
+        assert_eq!(
+            S::part_1(&parsed).unwrap().to_string(),
+            S::part_1(&reloaded).unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn test_model() {
+        for day in DAYS {
+            let json = (day.model)(&mut (day.generate)(0, 5).as_bytes()).unwrap();
+            assert!(serde_json::from_str::<serde_json::Value>(&json).is_ok(), "day {}", day.number);
+        }
+
+        // days with maps, nested arrays, recursion, options and enums holding data.
+        assert_reloads::<aoc_2021_day_04::Day04>(5);
+        assert_reloads::<aoc_2021_day_12::Day12>(5);
+        assert_reloads::<aoc_2021_day_16::Day16>(5);
+        assert_reloads::<aoc_2021_day_19::Day19>(5);
+        assert_reloads::<aoc_2021_day_20::Day20>(5);
+        assert_reloads::<aoc_2021_day_23::Day23>(5);
+        assert_reloads::<aoc_2021_day_24::Day24>(4);
+    }
+
+    #[test]
+    fn test_model_checked_on_load() {
+        use aoc_2021_day_04::Day04;
+        use aoc_2021_day_12::Day12;
+        use aoc_2021_day_20::Day20;
+        use aoc_2021_day_23::Day23;
+        use aoc_2021_day_25::Day25;
+
+        // only each board's numbers are dumped, and the marks rebuilt from them.
+        let day = find(4).unwrap();
+        let json = (day.model)(&mut (day.generate)(0, 5).as_bytes()).unwrap();
+        assert!(!json.contains("unmarked"), "{}", json);
+        serde_json::from_str::<<Day04 as Solution>::Input>(&json).unwrap();
+
+        let grid = (0..25).map(|n| (n % 24).to_string()).collect::<Vec<String>>();
+        let rows = grid.chunks(5).map(|row| format!("[{}]", row.join(","))).collect::<Vec<String>>();
+        let err = serde_json::from_str::<<Day04 as Solution>::Input>(
+            &format!(r#"[[1, 2], [{{"grid": [{}]}}]]"#, rows.join(",")),
+        );
+        assert!(err.unwrap_err().to_string().starts_with("0 appears more than once on a board"));
+
+        let err = serde_json::from_str::<<Day25 as Solution>::Input>(
+            r#"{"grid": {"rows": 2, "cols": 2, "cells": ["Empty", "East", "South"]}}"#,
+        );
+        assert!(err.unwrap_err().to_string().starts_with(
+            "3 cells cannot fill a grid of 2 rows and 2 columns"
+        ));
+
+        let err = serde_json::from_str::<<Day12 as Solution>::Input>(
+            r#"{
+                "adj_list": [[1], [0, 2], [1, 3], [2]],
+                "label_map": {"start": 0, "A": 1, "B": 2, "end": 3},
+                "vertex_map": {"0": "start", "1": "A", "2": "B", "3": "end"},
+                "is_small": [true, false, false, true]
+            }"#,
+        );
+        assert!(err.unwrap_err().to_string().starts_with("caves 1 and 2 are both big, yet joined"));
+        let err = serde_json::from_str::<<Day12 as Solution>::Input>(
+            r#"{
+                "adj_list": [[1], [0]],
+                "label_map": {"start": 0, "end": 1},
+                "vertex_map": {"0": "start", "1": "end"},
+                "is_small": [true]
+            }"#,
+        );
+        assert!(err.unwrap_err().to_string().starts_with("expected 2 caves in every map"));
+
+        let err = serde_json::from_str::<<Day20 as Solution>::Input>(
+            r#"{
+                "pixels": {"rows": 1, "cols": 1, "cells": [true]},
+                "background": false,
+                "enhancement_algo": [true, false]
+            }"#,
+        );
+        assert!(err.unwrap_err().to_string().starts_with(
+            "expected an enhancement algorithm of 512 pixels, found 2"
+        ));
+
+        let err = serde_json::from_str::<<Day23 as Solution>::Input>(
+            r#"{"rooms": [["Amber"], ["Bronze"], ["Copper"], ["Desert", "Amber"]]}"#,
+        );
+        assert!(err.unwrap_err().to_string().starts_with(
+            "rooms must all be of the same depth, from 1 to 2"
+        ));
+        let err = serde_json::from_str::<<Day23 as Solution>::Input>(
+            r#"{"rooms": [["Amber"], ["Bronze"], ["Copper"], ["Amber"]]}"#,
+        );
+        assert!(err.unwrap_err().to_string().starts_with(
+            "expected 1 amphipods of type A, found 2"
+        ));
+    }
+}
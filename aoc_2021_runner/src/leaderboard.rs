@@ -0,0 +1,295 @@
+//! Reports on a private leaderboard, from the JSON adventofcode.com exports for it.
+
+use serde::de::{self, Deserializer};
+use serde::Deserialize;
+
+use std::collections::BTreeMap;
+
+/// When the first puzzle of 2021 unlocked, at midnight EST, in seconds since the Unix epoch. The
+/// others followed a day apart.
+const FIRST_UNLOCK: u64 = 1_638_334_800;
+const DAY: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Absent for anonymous members.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    #[serde(deserialize_with = "timestamp")]
+    pub last_star_ts: u64,
+    /// When each part of each day was solved, by day and then part.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    #[serde(deserialize_with = "timestamp")]
+    pub get_star_ts: u64,
+}
+
+/// Timestamps are numbers in recent exports, but were strings in older ones.
+fn timestamp<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Timestamp {
+        Number(u64),
+        Text(String),
+    }
+
+    match Timestamp::deserialize(deserializer)? {
+        Timestamp::Number(n) => Ok(n),
+        Timestamp::Text(s) => s.parse().map_err(de::Error::custom),
+    }
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// How long after the puzzle unlocked the member solved a part of a day, if they did.
+    pub fn solve_time(&self, day: u8, part: u8) -> Option<u64> {
+        let star = self.completion_day_level.get(&day)?.get(&part)?;
+        let unlock = FIRST_UNLOCK + (day as u64).saturating_sub(1) * DAY;
+        Some(star.get_star_ts.saturating_sub(unlock))
+    }
+}
+
+pub fn parse(json: &str) -> Result<Leaderboard, String> {
+    let leaderboard: Leaderboard = serde_json::from_str(json).map_err(|e| e.to_string())?;
+
+    if leaderboard.event != "2021" {
+        return Err(format!("expected a 2021 leaderboard, found {}", leaderboard.event));
+    }
+
+    Ok(leaderboard)
+}
+
+/// A table of cells under a header, ready to print either as aligned text or as Markdown.
+pub struct Table {
+    pub header: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn markdown(&self) -> String {
+        let line = |cells: &[String]| {
+            let cells = cells.iter().map(|c| c.replace('|', "\\|")).collect::<Vec<String>>();
+            format!("| {} |\n", cells.join(" | "))
+        };
+
+        let mut result = line(&self.header);
+        result += &line(&vec!["---".to_string(); self.header.len()]);
+        for row in &self.rows {
+            result += &line(row);
+        }
+
+        result
+    }
+}
+
+fn strings(cells: &[&str]) -> Vec<String> {
+    cells.iter().map(|c| c.to_string()).collect()
+}
+
+/// Members ordered by local score, ties going to whoever got their last star first.
+fn ranked(leaderboard: &Leaderboard) -> Vec<&Member> {
+    let mut members = leaderboard.members.values().collect::<Vec<&Member>>();
+    members.sort_by_key(|m| (u32::MAX - m.local_score, m.last_star_ts, m.id));
+    members
+}
+
+/// Every member's rank, local score and number of stars. Members with the same score share a rank.
+pub fn rankings(leaderboard: &Leaderboard) -> Table {
+    let members = ranked(leaderboard);
+
+    let rows = members.iter()
+        .enumerate()
+        .map(|(i, member)| {
+            let rank = members.iter().position(|m| m.local_score == member.local_score).unwrap_or(i);
+            vec![
+                (rank + 1).to_string(),
+                member.display_name(),
+                member.local_score.to_string(),
+                member.stars.to_string(),
+            ]
+        })
+        .collect();
+
+    Table { header: strings(&["Rank", "Name", "Score", "Stars"]), rows }
+}
+
+/// How long each member who solved a part of `day` took, counting from when it unlocked, fastest
+/// to solve both parts first.
+pub fn day_times(leaderboard: &Leaderboard, day: u8) -> Table {
+    let mut members = leaderboard.members.values()
+        .filter(|m| m.solve_time(day, 1).is_some())
+        .collect::<Vec<&Member>>();
+    members.sort_by_key(|m| {
+        (m.solve_time(day, 2).is_none(), m.solve_time(day, 2), m.solve_time(day, 1))
+    });
+
+    let rows = members.iter()
+        .map(|m| {
+            let time = |part| m.solve_time(day, part).map_or_else(|| "-".to_string(), format_time);
+            vec![m.display_name(), time(1), time(2)]
+        })
+        .collect();
+
+    Table { header: strings(&["Name", "Part 1", "Part 2"]), rows }
+}
+
+/// How many members solved both parts of `day`, and how many only the first. An export with only
+/// the second part solved counts as both.
+pub fn star_counts(leaderboard: &Leaderboard, day: u8) -> (usize, usize) {
+    let solved = |m: &&Member, part| m.solve_time(day, part).is_some();
+    let members = leaderboard.members.values();
+
+    (
+        members.clone().filter(|m| solved(m, 2)).count(),
+        members.filter(|m| solved(m, 1) && !solved(m, 2)).count(),
+    )
+}
+
+/// Formats seconds as e.g. "01:02:03", or "2d 01:02:03" if more than a day.
+pub fn format_time(seconds: u64) -> String {
+    let hms = format!("{:02}:{:02}:{:02}", seconds % DAY / 3600, seconds % 3600 / 60, seconds % 60);
+
+    match seconds / DAY {
+        0 => hms,
+        days => format!("{}d {}", days, hms),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // in the shape of a real export. Older exports had timestamps as strings, as in Bob's.
+    const LEADERBOARD: &str = r#"{
+        "event": "2021",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "Alice", "stars": 3, "local_score": 7, "global_score": 0,
+                "last_star_ts": 1638424800,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1638335400, "star_index": 0},
+                        "2": {"get_star_ts": 1638336000, "star_index": 2}
+                    },
+                    "2": {"1": {"get_star_ts": 1638424800, "star_index": 5}}
+                }
+            },
+            "2": {
+                "id": 2, "name": "Bob", "stars": 2, "local_score": 4, "global_score": 0,
+                "last_star_ts": "1638336500",
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": "1638335500"},
+                        "2": {"get_star_ts": "1638336500"}
+                    }
+                }
+            },
+            "3": {
+                "id": 3, "name": null, "stars": 1, "local_score": 4, "global_score": 0,
+                "last_star_ts": 1638600000,
+                "completion_day_level": {"1": {"1": {"get_star_ts": 1638600000}}}
+            },
+            "4": {
+                "id": 4, "name": "Dan", "stars": 0, "local_score": 0, "global_score": 0,
+                "last_star_ts": 0, "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_parse() {
+        let leaderboard = parse(LEADERBOARD).unwrap();
+        assert_eq!(4, leaderboard.members.len());
+
+        let bob = &leaderboard.members["2"];
+        assert_eq!(1638336500, bob.last_star_ts);
+        assert_eq!(Some(700), bob.solve_time(1, 1));
+        assert_eq!(None, bob.solve_time(2, 1));
+
+        let error = parse(&LEADERBOARD.replace("\"2021\"", "\"2020\"")).unwrap_err();
+        assert_eq!("expected a 2021 leaderboard, found 2020", error);
+    }
+
+    #[test]
+    fn test_rankings() {
+        let leaderboard = parse(LEADERBOARD).unwrap();
+
+        assert_eq!(
+            vec![
+                strings(&["1", "Alice", "7", "3"]),
+                strings(&["2", "Bob", "4", "2"]),
+                strings(&["2", "(anonymous user #3)", "4", "1"]),
+                strings(&["4", "Dan", "0", "0"]),
+            ],
+            rankings(&leaderboard).rows
+        );
+    }
+
+    #[test]
+    fn test_day_times() {
+        let leaderboard = parse(LEADERBOARD).unwrap();
+
+        assert_eq!(
+            vec![
+                strings(&["Alice", "00:10:00", "00:20:00"]),
+                strings(&["Bob", "00:11:40", "00:28:20"]),
+                strings(&["(anonymous user #3)", "3d 01:40:00", "-"]),
+            ],
+            day_times(&leaderboard, 1).rows
+        );
+        assert_eq!((2, 1), star_counts(&leaderboard, 1));
+        assert_eq!((0, 1), star_counts(&leaderboard, 2));
+        assert_eq!((0, 0), star_counts(&leaderboard, 3));
+    }
+
+    #[test]
+    fn test_odd_exports() {
+        // a second star without the first, and a day that does not exist, must not underflow.
+        let leaderboard = parse(r#"{
+            "event": "2021",
+            "members": {
+                "1": {
+                    "id": 1, "name": "Eve", "stars": 2, "local_score": 2,
+                    "last_star_ts": 1638336000,
+                    "completion_day_level": {
+                        "0": {"1": {"get_star_ts": 1638248400}},
+                        "1": {"2": {"get_star_ts": 1638336000}}
+                    }
+                }
+            }
+        }"#).unwrap();
+
+        assert_eq!((1, 0), star_counts(&leaderboard, 1));
+        assert_eq!((0, 1), star_counts(&leaderboard, 0));
+        assert_eq!(Some(0), leaderboard.members["1"].solve_time(0, 1));
+        assert_eq!(Some(1200), leaderboard.members["1"].solve_time(1, 2));
+    }
+
+    #[test]
+    fn test_markdown() {
+        let table = Table {
+            header: strings(&["Name", "Stars"]),
+            rows: vec![strings(&["Alice", "3"]), strings(&["B|ob", "2"])],
+        };
+        assert_eq!(
+            "| Name | Stars |\n| --- | --- |\n| Alice | 3 |\n| B\\|ob | 2 |\n",
+            table.markdown()
+        );
+    }
+}
@@ -0,0 +1,630 @@
+mod batch;
+mod client;
+mod config;
+mod days;
+mod leaderboard;
+#[cfg(test)]
+mod mock_server;
+mod scaffold;
+mod submit;
+mod timing;
+mod verify;
+
+use aoc_2021_common::parse::parse_path;
+use aoc_2021_common::{print_answers, run_path, Answer, Params, Part};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use rayon::prelude::*;
+use serde::Serialize;
+use tracing::Level;
+use tracing_subscriber::fmt::MakeWriter;
+
+use std::path::{Path, PathBuf};
+use std::fs;
+use std::io;
+use std::iter;
+use std::process;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use client::Client;
+use days::{Day, DAYS};
+use leaderboard::Table;
+use submit::{Attempt, AttemptLog};
+use timing::Timing;
+use verify::{Expected, Outcome, Tally};
+
+#[derive(Parser)]
+#[command(name = "aoc2021", about = "Advent of Code 2021 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day with --all.
+    Run(RunArgs),
+    /// Solve every day for every user with inputs in inputs/<user>/day_NN.txt, printing their
+    /// answers side by side.
+    Batch(BatchArgs),
+    /// Download a day's puzzle input into inputs/<user>/day_NN.txt, unless it is already there.
+    Fetch(FetchArgs),
+    /// Solve a part of a day and submit the answer, unless it is already known to be wrong.
+    Submit(SubmitArgs),
+    /// Report on a private leaderboard from its JSON export: the rankings, and how many solved
+    /// each day and how quickly.
+    Leaderboard(LeaderboardArgs),
+    /// Create the crate for a new day from a template, and register it with the workspace, the
+    /// runner, the benchmarks and the fuzz targets.
+    NewDay(NewDayArgs),
+    /// Print a random but valid input for a day, e.g. to pipe into `run <day> --input -`.
+    Generate(GenerateArgs),
+    /// Print the model a day parses its input into as JSON, e.g. to inspect or cache it.
+    DumpModel(DumpModelArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// The day to solve.
+    #[arg(required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Solve every day, in parallel, and finish with a table of how long each took.
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+
+    /// Number of threads to solve the days on with --all. Defaults to one per CPU.
+    #[arg(long, requires = "all")]
+    jobs: Option<usize>,
+
+    /// Only solve the given part (1 or 2).
+    #[arg(long)]
+    part: Option<Part>,
+
+    /// Puzzle input file, or "-" for stdin. Defaults to the day crate's input.txt.
+    #[arg(long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+
+    /// Solve the given user's input, from inputs/<user>/day_NN.txt.
+    #[arg(long, conflicts_with_all = ["input", "verify"])]
+    user: Option<String>,
+
+    /// Check the answers against those recorded in each day's answers.txt, failing on a mismatch.
+    #[arg(long, conflicts_with = "input")]
+    verify: bool,
+
+    /// How to print the answers.
+    #[arg(long, value_enum, default_value_t = Format::Text, conflicts_with = "verify")]
+    format: Format,
+
+    /// Override one of the day's params, e.g. --param steps=1000. Repeat to override several. An
+    /// unknown name lists the day's params.
+    #[arg(long = "param", value_name = "NAME=VALUE", conflicts_with_all = ["all", "verify"])]
+    params: Vec<String>,
+
+    /// Log each step of the solvers that trace them, such as every Dijkstra pop on day 15, to
+    /// stderr.
+    #[arg(long)]
+    trace: bool,
+
+    /// Log the trace to the given file instead of stderr. Implies --trace.
+    #[arg(long, value_name = "FILE")]
+    trace_file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct BatchArgs {
+    /// Only solve the given day.
+    day: Option<u8>,
+
+    /// Only solve the given part (1 or 2).
+    #[arg(long)]
+    part: Option<Part>,
+
+    /// Directory holding a subdirectory of inputs for each user. Defaults to the workspace's
+    /// inputs directory.
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// The day to download the input of.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Settings file holding the session token. Defaults to aoc2021.toml at the root of the
+    /// workspace.
+    #[arg(long)]
+    config: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// The day to submit an answer for.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The part to submit an answer for (1 or 2).
+    part: Part,
+
+    /// Submit this answer rather than solving the input, e.g. letters read off a rendered answer.
+    #[arg(long)]
+    answer: Option<String>,
+
+    /// Puzzle input file to solve. Defaults to the fetched input in inputs/<user>/day_NN.txt.
+    #[arg(long, conflicts_with = "answer")]
+    input: Option<PathBuf>,
+
+    /// Settings file holding the session token. Defaults to aoc2021.toml at the root of the
+    /// workspace.
+    #[arg(long)]
+    config: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct LeaderboardArgs {
+    /// The leaderboard's JSON export, as downloaded from its page on adventofcode.com.
+    path: PathBuf,
+
+    /// Only report on the given day, besides the rankings.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Print Markdown tables, e.g. to paste into an issue or a wiki page.
+    #[arg(long)]
+    markdown: bool,
+}
+
+#[derive(Args)]
+struct NewDayArgs {
+    /// The day to create.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The puzzle's title, for the solution's doc comment.
+    #[arg(long)]
+    title: Option<String>,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// The day to generate an input for.
+    day: u8,
+
+    /// Seed for the random number generator; the same seed always gives the same input.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// How large an input to generate, e.g. the number of lines or the width of a grid. Defaults
+    /// to the size of the real puzzle input.
+    #[arg(long)]
+    size: Option<usize>,
+}
+
+#[derive(Args)]
+struct DumpModelArgs {
+    /// The day whose model to print.
+    day: u8,
+
+    /// Puzzle input file, or "-" for stdin. Defaults to the day crate's input.txt.
+    #[arg(long)]
+    input: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    /// An array of {day, part, answer, elapsed_ns} records, one per solved part.
+    Json,
+}
+
+/// A solved part, as printed by `--format json`.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    elapsed_ns: u64,
+}
+
+impl<'a> From<&'a Answer> for Record<'a> {
+    fn from(answer: &'a Answer) -> Self {
+        Self {
+            day: answer.day,
+            part: answer.part.number(),
+            answer: &answer.value,
+            elapsed_ns: answer.elapsed.as_nanos() as u64,
+        }
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Batch(args) => batch(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::Leaderboard(args) => report_leaderboard(args),
+        Command::NewDay(args) => new_day(args),
+        Command::Generate(args) => generate(args),
+        Command::DumpModel(args) => dump_model(args),
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+/// Logs the solvers' trace events to `writer`, keeping them apart from the answers on stdout.
+fn init_tracing<W>(writer: W, ansi: bool)
+where
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    tracing_subscriber::fmt()
+        .with_max_level(Level::TRACE)
+        .with_writer(writer)
+        .with_ansi(ansi)
+        .init();
+}
+
+/// A day's answers, and how long it took to read and parse its input.
+struct Solved {
+    day: &'static Day,
+    answers: Vec<Answer>,
+    parse_elapsed: Duration,
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    if let Some(path) = &args.trace_file {
+        let file = fs::File::create(path)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        init_tracing(Mutex::new(file), false);
+    } else if args.trace {
+        init_tracing(io::stderr, true);
+    }
+
+    let start = Instant::now();
+    let mut threads = 1;
+
+    let results = if args.all {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(args.jobs.unwrap_or(0))
+            .build()
+            .map_err(|e| e.to_string())?;
+        threads = pool.current_num_threads();
+
+        pool.install(|| {
+            DAYS.par_iter()
+                .map(|day| {
+                    let params = Params::defaults(day.params);
+                    solve(day, &input_path(&args, day), args.part, &params)
+                })
+                .collect()
+        })
+    } else {
+        let day = find_day(args.day.unwrap())?;
+        let params = Params::parse(day.params, &args.params)
+            .map_err(|e| format!("day {:02}: {}", day.number, e))?;
+        vec![solve(day, &input_path(&args, day), args.part, &params)]
+    };
+
+    let wall_clock = start.elapsed();
+
+    let mut tally = Tally::default();
+    let mut solved = vec![];
+    let mut timings = vec![];
+    let mut errors = 0;
+
+    for (i, result) in results.into_iter().enumerate() {
+        // a day that cannot be solved is reported along with the rest, rather than cutting them
+        // short.
+        let Solved { day, answers, parse_elapsed } = match result {
+            Ok(solved) => solved,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                errors += 1;
+                continue;
+            },
+        };
+
+        timings.push(Timing::new(day.number, parse_elapsed, &answers));
+
+        if args.verify {
+            if let Err(e) = verify_answers(day, &answers, &mut tally) {
+                eprintln!("error: {}", e);
+                errors += 1;
+            }
+        } else if args.format == Format::Json {
+            solved.extend(answers);
+        } else {
+            if i > 0 {
+                println!();
+            }
+            print_answers(day.number, &answers);
+        }
+    }
+
+    if args.format == Format::Json {
+        let records = solved.iter().map(Record::from).collect::<Vec<Record>>();
+        let json = serde_json::to_string_pretty(&records).map_err(|e| e.to_string())?;
+        println!("{}", json);
+    } else if args.all {
+        println!("\n{}", timing::table(&timings));
+        println!("Solved in {:.3?} on {} thread(s)", wall_clock, threads);
+    }
+
+    if args.verify {
+        println!("\n{} passed, {} failed, {} missing", tally.passed, tally.failed, tally.missing);
+
+        if tally.failed > 0 {
+            return Err(format!("{} answer(s) did not match the recorded answers", tally.failed));
+        }
+    }
+
+    if errors > 0 {
+        return Err(format!("{} day(s) failed with an error", errors));
+    }
+
+    Ok(())
+}
+
+/// The input `run` should solve for a day.
+fn input_path(args: &RunArgs, day: &Day) -> PathBuf {
+    match (&args.input, &args.user) {
+        (Some(path), _) => path.clone(),
+        (None, Some(user)) => batch::input_path(&batch::default_dir(), user, day.number),
+        (None, None) => day.default_input(),
+    }
+}
+
+fn solve(
+    day: &'static Day,
+    path: &Path,
+    part: Option<Part>,
+    params: &Params,
+) -> Result<Solved, String> {
+    let start = Instant::now();
+    let answers = run_path(day.run, path, part, params)
+        .map_err(|e| format!("day {:02}: {}", day.number, e))?;
+
+    // each part times itself, leaving the rest to reading and parsing the input.
+    let solving = answers.iter().map(|a| a.elapsed).sum();
+    let parse_elapsed = start.elapsed().saturating_sub(solving);
+
+    Ok(Solved { day, answers, parse_elapsed })
+}
+
+fn batch(args: BatchArgs) -> Result<(), String> {
+    let part = args.part;
+    let dir = args.inputs.unwrap_or_else(batch::default_dir);
+    let users = batch::users(&dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+
+    let days = match args.day {
+        Some(number) => vec![find_day(number)?],
+        None => DAYS.iter().collect(),
+    };
+
+    // one job per day and user, in order, skipping users without an input for the day.
+    let jobs = days.iter()
+        .flat_map(|day| users.iter().map(move |user| (*day, user)))
+        .collect::<Vec<(&Day, &String)>>();
+
+    let results = jobs.par_iter()
+        .map(|(day, user)| {
+            let path = batch::input_path(&dir, user, day.number);
+            let params = Params::defaults(day.params);
+            if path.exists() { Some(solve(day, &path, part, &params)) } else { None }
+        })
+        .collect::<Vec<Option<Result<Solved, String>>>>();
+
+    let parts = match part {
+        Some(p) => vec![p],
+        None => Part::ALL.to_vec(),
+    };
+
+    let header = iter::once(String::new()).chain(users.iter().cloned()).collect::<Vec<String>>();
+    let mut errors = vec![];
+    let mut printed = false;
+
+    for (day, results) in days.iter().zip(results.chunks(users.len().max(1))) {
+        if results.iter().all(Option::is_none) {
+            continue;
+        }
+
+        let rows = parts.iter()
+            .map(|part| {
+                let answers = results.iter().map(|result| match result {
+                    Some(Ok(solved)) => solved.answers.iter()
+                        .find(|a| a.part == *part)
+                        .map_or_else(String::new, |a| a.value.clone()),
+                    Some(Err(_)) => "error".to_string(),
+                    None => "-".to_string(),
+                });
+
+                iter::once(format!("Part {}", part.number())).chain(answers).collect()
+            })
+            .collect::<Vec<Vec<String>>>();
+
+        errors.extend(results.iter().flatten().filter_map(|r| r.as_ref().err()));
+
+        if printed {
+            println!();
+        }
+        println!("*-*-*-*-*- Day {:02} -*-*-*-*-*\n", day.number);
+        print!("{}", batch::table(&header, &rows));
+        printed = true;
+    }
+
+    if !printed {
+        return Err(format!("no inputs found in {}", dir.display()));
+    }
+
+    for error in &errors {
+        eprintln!("error: {}", error);
+    }
+
+    if !errors.is_empty() {
+        return Err(format!("{} input(s) could not be solved", errors.len()));
+    }
+
+    Ok(())
+}
+
+fn fetch(args: FetchArgs) -> Result<(), String> {
+    let config = config::load(&args.config.unwrap_or_else(config::default_path))?;
+    let client = Client::from_config(&config);
+    let path = batch::input_path(&batch::default_dir(), &config.user, args.day);
+
+    if client::fetch_into(&client, args.day, &path)? {
+        println!("Fetched day {:02} into {}", args.day, path.display());
+    } else {
+        println!("Day {:02} is already in {}", args.day, path.display());
+    }
+
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<(), String> {
+    let config = config::load(&args.config.unwrap_or_else(config::default_path))?;
+    let (day, part) = (args.day, args.part);
+
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let path = args.input
+                .unwrap_or_else(|| batch::input_path(&batch::default_dir(), &config.user, day));
+            let day = find_day(day)?;
+            let mut solved = solve(day, &path, Some(part), &Params::defaults(day.params))?;
+            solved.answers.remove(0).value
+        },
+    };
+
+    if answer.contains('\n') {
+        let reason = "the answer spans several lines, pass what it reads with --answer";
+        return Err(format!("{}:\n{}", reason, answer));
+    }
+
+    // kept with the user's inputs, as each account has its own answers.
+    let log_path = batch::default_dir().join(&config.user).join("attempts.jsonl");
+    let mut log = AttemptLog::load(&log_path)?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map_err(|e| e.to_string())?.as_secs();
+
+    log.check(day, part, &answer, now)
+        .map_err(|reason| format!("not submitting {}: {}", answer, reason))?;
+
+    let (verdict, lockout) = Client::from_config(&config).submit(day, part, &answer)?;
+
+    println!("Day {:02} part {}, {}: {}", day, part.number(), answer, verdict);
+
+    log.record(Attempt { day, part: part.number(), answer, verdict, time: now, lockout })?;
+
+    Ok(())
+}
+
+fn report_leaderboard(args: LeaderboardArgs) -> Result<(), String> {
+    let leaderboard = fs::read_to_string(&args.path)
+        .map_err(|e| e.to_string())
+        .and_then(|json| leaderboard::parse(&json))
+        .map_err(|e| format!("{}: {}", args.path.display(), e))?;
+
+    let heading = |title: &str| {
+        if args.markdown {
+            println!("## {}\n", title);
+        } else {
+            println!("*-*-*-*-*- {} -*-*-*-*-*\n", title);
+        }
+    };
+    let table = |table: Table| {
+        if args.markdown {
+            print!("{}", table.markdown());
+        } else {
+            print!("{}", batch::table(&table.header, &table.rows));
+        }
+    };
+
+    heading("Rankings");
+    table(leaderboard::rankings(&leaderboard));
+
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => (1..=25).collect(),
+    };
+
+    for day in days {
+        let (both, first_only) = leaderboard::star_counts(&leaderboard, day);
+        if both + first_only == 0 && args.day.is_none() {
+            continue;
+        }
+
+        println!();
+        heading(&format!("Day {:02}", day));
+        println!("{} with both stars, {} with only the first\n", both, first_only);
+        table(leaderboard::day_times(&leaderboard, day));
+    }
+
+    Ok(())
+}
+
+fn new_day(args: NewDayArgs) -> Result<(), String> {
+    let files = scaffold::new_day(&scaffold::default_root(), args.day, args.title.as_deref())?;
+
+    for file in files {
+        println!("{}", file.display());
+    }
+
+    Ok(())
+}
+
+fn generate(args: GenerateArgs) -> Result<(), String> {
+    let day = find_day(args.day)?;
+
+    print!("{}", (day.generate)(args.seed, args.size.unwrap_or(day.default_size)));
+
+    Ok(())
+}
+
+fn dump_model(args: DumpModelArgs) -> Result<(), String> {
+    let day = find_day(args.day)?;
+    let path = args.input.unwrap_or_else(|| day.default_input());
+
+    let json = parse_path(&path, day.model).map_err(|e| format!("day {:02}: {}", day.number, e))?;
+    println!("{}", json);
+
+    Ok(())
+}
+
+fn find_day(number: u8) -> Result<&'static Day, String> {
+    days::find(number).ok_or(format!("day {} is not implemented", number))
+}
+
+fn verify_answers(day: &Day, answers: &[Answer], tally: &mut Tally) -> Result<(), String> {
+    let path = day.answers();
+
+    let expected = if path.exists() {
+        parse_path(&path, |input| verify::parse_answers(input))
+            .map_err(|e| format!("day {:02}: {}", day.number, e))?
+    } else {
+        Expected::default()
+    };
+
+    for answer in answers {
+        let outcome = verify::check(&expected, answer);
+
+        let status = match &outcome {
+            Outcome::Pass => "pass".to_string(),
+            Outcome::Fail { expected } => {
+                format!("FAIL\n  expected: {}\n  found:    {}", expected, answer.value)
+            },
+            Outcome::Missing => "missing".to_string(),
+        };
+        println!("Day {:02} part {}: {}", day.number, answer.part.number(), status);
+
+        tally.record(&outcome);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+//! A local HTTP server standing in for adventofcode.com in tests.
+
+use tiny_http::{Response, Server};
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A request as received by the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Received {
+    pub method: String,
+    pub path: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// Answers each request with the next of a list of canned responses, recording the requests.
+pub struct MockServer {
+    server: Arc<Server>,
+    url: String,
+    received: Arc<Mutex<Vec<Received>>>,
+}
+
+impl MockServer {
+    /// Starts a server on a free local port, replaying `responses` as (status, body) pairs.
+    pub fn start(responses: &[(u16, &str)]) -> Self {
+        let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let received = Arc::new(Mutex::new(vec![]));
+
+        let responses = responses.iter()
+            .map(|(status, body)| (*status, body.to_string()))
+            .collect::<Vec<(u16, String)>>();
+
+        let (thread_server, thread_received) = (Arc::clone(&server), Arc::clone(&received));
+        thread::spawn(move || {
+            for (status, body) in responses {
+                // fails once the server is dropped.
+                let mut request = match thread_server.recv() {
+                    Ok(request) => request,
+                    Err(_) => return,
+                };
+
+                let mut request_body = String::new();
+                request.as_reader().read_to_string(&mut request_body).unwrap();
+
+                let cookie = request.headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string());
+
+                thread_received.lock().unwrap().push(Received {
+                    method: request.method().to_string(),
+                    path: request.url().to_string(),
+                    cookie,
+                    body: request_body,
+                });
+
+                request.respond(Response::from_string(body).with_status_code(status)).unwrap();
+            }
+        });
+
+        Self { server, url, received }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The requests received so far, in order.
+    pub fn received(&self) -> Vec<Received> {
+        self.received.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+    }
+}
@@ -0,0 +1,237 @@
+//! Creating the crate for a new day, and registering it everywhere the other days are listed.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = include_str!("../templates/day/Cargo.toml.template");
+const LIB_RS: &str = include_str!("../templates/day/lib.rs.template");
+const MAIN_RS: &str = include_str!("../templates/day/main.rs.template");
+const GENERATE_RS: &str = include_str!("../templates/day/generate.rs.template");
+const FUZZ_TARGET: &str = include_str!("../templates/day/fuzz_target.rs.template");
+const FUZZ_BIN: &str = include_str!("../templates/day/fuzz_bin.toml.template");
+
+/// Finds which day a line registering a day is for.
+type DayOf = fn(&str) -> Option<u8>;
+
+/// The root of the workspace the runner was built in.
+pub fn default_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// Creates the crate for `day` in the workspace at `root`, with an empty input, example input and
+/// answers file, and registers it with the workspace, the runner, the benchmarks and the fuzz
+/// targets. Returns the files created or changed. Nothing is written if the day already exists or
+/// cannot be registered.
+pub fn new_day(root: &Path, day: u8, title: Option<&str>) -> Result<Vec<PathBuf>, String> {
+    let nn = format!("{:02}", day);
+    let krate = format!("aoc_2021_day_{}", nn);
+    let crate_dir = root.join(&krate);
+
+    if crate_dir.exists() {
+        return Err(format!("day {} already exists in {}", day, crate_dir.display()));
+    }
+
+    let heading = match title {
+        Some(title) if title.ends_with(|c: char| c.is_ascii_punctuation()) => {
+            format!("Day {}: {}", day, title)
+        },
+        Some(title) => format!("Day {}: {}.", day, title),
+        None => format!("Day {}.", day),
+    };
+    let render = |template: &str| {
+        template.replace("{{day}}", &day.to_string())
+            .replace("{{nn}}", &nn)
+            .replace("{{heading}}", &heading)
+    };
+
+    let mut files = vec![
+        (crate_dir.join("Cargo.toml"), render(CARGO_TOML)),
+        (crate_dir.join("src").join("lib.rs"), render(LIB_RS)),
+        (crate_dir.join("src").join("main.rs"), render(MAIN_RS)),
+        (crate_dir.join("src").join("generate.rs"), render(GENERATE_RS)),
+        (crate_dir.join("input.txt"), String::new()),
+        (crate_dir.join("test_input.txt"), String::new()),
+        (crate_dir.join("answers.txt"), String::new()),
+        (root.join("fuzz/fuzz_targets").join(format!("day_{}.rs", nn)), render(FUZZ_TARGET)),
+    ];
+
+    let dependency = format!("{} = {{ path = \"../{}\" }}", krate, krate);
+    // the runner dumps every day's model, so needs them serializable.
+    let runner_dependency =
+        format!("{} = {{ path = \"../{}\", features = [\"serde\"] }}", krate, krate);
+    let entry = format!("    day!({}, {}, Day{}),", day, krate, nn);
+    let bench = format!(
+        "    bench_day::<{}::Day{}>(c, input!(\"{}\", \"test_input.txt\"), \
+         input!(\"{}\", \"input.txt\"), 100);",
+        krate, nn, nn, nn
+    );
+    let registrations: [(&str, String, DayOf); 5] = [
+        ("Cargo.toml", format!("    \"{}\",", krate), member_day),
+        ("aoc_2021_runner/Cargo.toml", runner_dependency, dependency_day),
+        ("aoc_2021_runner/src/days.rs", entry, days_entry_day),
+        ("aoc_2021_runner/benches/solutions.rs", bench, bench_entry_day),
+        ("fuzz/Cargo.toml", dependency, dependency_day),
+    ];
+
+    for (file, line, day_of) in registrations.iter() {
+        let path = root.join(file);
+        let text = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        let mut text = insert_line(&text, line, day, *day_of)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        if *file == "fuzz/Cargo.toml" {
+            text += &render(FUZZ_BIN);
+        }
+
+        files.push((path, text));
+    }
+
+    for (path, contents) in &files {
+        let write = || {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(path, contents)
+        };
+        write().map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// The day of a workspace member, e.g. `    "aoc_2021_day_07",`.
+fn member_day(line: &str) -> Option<u8> {
+    line.trim().strip_prefix("\"aoc_2021_day_")?.get(..2)?.parse().ok()
+}
+
+/// The day of a dependency on a day's crate, e.g. `aoc_2021_day_07 = { path = ... }`.
+fn dependency_day(line: &str) -> Option<u8> {
+    let rest = line.strip_prefix("aoc_2021_day_")?;
+    if !rest.contains("path") {
+        return None;
+    }
+    rest.get(..2)?.parse().ok()
+}
+
+/// The day of an entry in the runner's list of days, e.g. `    day!(7, aoc_2021_day_07, Day07),`.
+fn days_entry_day(line: &str) -> Option<u8> {
+    line.trim().strip_prefix("day!(")?.split(',').next()?.parse().ok()
+}
+
+/// The day of an entry in the runner's benchmarks, e.g.
+/// `    bench_day::<aoc_2021_day_07::Day07>(c, input!("07", "test_input.txt"), ...);`.
+fn bench_entry_day(line: &str) -> Option<u8> {
+    line.trim().strip_prefix("bench_day::<aoc_2021_day_")?.get(..2)?.parse().ok()
+}
+
+/// Inserts `line` among the lines of `text` that belong to a day, as told by `day_of`, keeping
+/// them in order. Fails if no lines belong to a day, or one already belongs to `day`.
+fn insert_line(text: &str, line: &str, day: u8, day_of: DayOf) -> Result<String, String> {
+    let lines = text.lines().collect::<Vec<&str>>();
+
+    let days = lines.iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, day_of(l)?)))
+        .collect::<Vec<(usize, u8)>>();
+
+    if days.iter().any(|(_, d)| *d == day) {
+        return Err(format!("day {} is already registered", day));
+    }
+
+    let (last, _) = days.last().ok_or("could not find where the days are registered")?;
+    let index = days.iter().find(|(_, d)| *d > day).map_or(last + 1, |(i, _)| *i);
+
+    let mut result = lines[..index].to_vec();
+    result.push(line);
+    result.extend_from_slice(&lines[index..]);
+
+    Ok(result.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env;
+    use std::process;
+
+    #[test]
+    fn test_insert_line() {
+        let days = "pub const DAYS: &[Day] = &[\n    day!(1, aoc_2021_day_01, Day01),\n    \
+                    day!(3, aoc_2021_day_03, Day03),\n];\n";
+
+        assert_eq!(
+            "pub const DAYS: &[Day] = &[\n    day!(1, aoc_2021_day_01, Day01),\n    \
+             day!(2, aoc_2021_day_02, Day02),\n    day!(3, aoc_2021_day_03, Day03),\n];\n",
+            insert_line(days, "    day!(2, aoc_2021_day_02, Day02),", 2, days_entry_day).unwrap()
+        );
+        assert_eq!(
+            "pub const DAYS: &[Day] = &[\n    day!(1, aoc_2021_day_01, Day01),\n    \
+             day!(3, aoc_2021_day_03, Day03),\n    day!(4, aoc_2021_day_04, Day04),\n];\n",
+            insert_line(days, "    day!(4, aoc_2021_day_04, Day04),", 4, days_entry_day).unwrap()
+        );
+        let existing = "    day!(3, aoc_2021_day_03, Day03),";
+        assert!(insert_line(days, existing, 3, days_entry_day).is_err());
+        assert!(insert_line("[workspace]\n", "    \"aoc_2021_day_01\",", 1, member_day).is_err());
+
+        assert_eq!(Some(7), member_day("    \"aoc_2021_day_07\","));
+        assert_eq!(Some(7), dependency_day("aoc_2021_day_07 = { path = \"../aoc_2021_day_07\" }"));
+        assert_eq!(None, dependency_day("aoc_2021_common = { path = \"../aoc_2021_common\" }"));
+        let bench = "    bench_day::<aoc_2021_day_07::Day07>(c, a, b, 100);";
+        assert_eq!(Some(7), bench_entry_day(bench));
+        assert_eq!(None, bench_entry_day("fn bench_day<S: Solution>(c: &mut Criterion) {"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = env::temp_dir().join(format!("aoc2021_scaffold_{}", process::id()));
+        let write = |file: &str, text: &str| {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        };
+
+        write("Cargo.toml", "[workspace]\nmembers = [\n    \"aoc_2021_day_01\",\n]\n");
+        let dependencies = "[dependencies]\naoc_2021_day_01 = { path = \"../aoc_2021_day_01\" }\n";
+        write("aoc_2021_runner/Cargo.toml", dependencies);
+        write("aoc_2021_runner/src/days.rs", "&[\n    day!(1, aoc_2021_day_01, Day01),\n];\n");
+        write(
+            "aoc_2021_runner/benches/solutions.rs",
+            "fn solutions(c: &mut Criterion) {\n    \
+             bench_day::<aoc_2021_day_01::Day01>(c, a, b, 100);\n}\n",
+        );
+        write("fuzz/Cargo.toml", dependencies);
+
+        let created = new_day(&root, 2, Some("Dive!"));
+        let again = new_day(&root, 2, None);
+        let read = |file: &str| fs::read_to_string(root.join(file)).unwrap();
+        let (workspace, runner, days, benches, fuzz, lib) = (
+            read("Cargo.toml"),
+            read("aoc_2021_runner/Cargo.toml"),
+            read("aoc_2021_runner/src/days.rs"),
+            read("aoc_2021_runner/benches/solutions.rs"),
+            read("fuzz/Cargo.toml"),
+            read("aoc_2021_day_02/src/lib.rs"),
+        );
+        let answers_exist = root.join("aoc_2021_day_02/answers.txt").exists();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(13, created.unwrap().len());
+        assert!(again.unwrap_err().starts_with("day 2 already exists"));
+
+        assert!(workspace.contains("    \"aoc_2021_day_01\",\n    \"aoc_2021_day_02\",\n]"));
+        assert!(runner.contains(
+            "aoc_2021_day_02 = { path = \"../aoc_2021_day_02\", features = [\"serde\"] }\n"
+        ));
+        assert!(days.contains("    day!(2, aoc_2021_day_02, Day02),\n];"));
+        assert!(benches.contains(
+            "    bench_day::<aoc_2021_day_02::Day02>(c, input!(\"02\", \"test_input.txt\"), \
+             input!(\"02\", \"input.txt\"), 100);\n}"
+        ));
+        assert!(fuzz.contains("aoc_2021_day_02 = { path = \"../aoc_2021_day_02\" }\n"));
+        assert!(fuzz.contains("[[bin]]\nname = \"day_02\"\npath = \"fuzz_targets/day_02.rs\"\n"));
+        assert!(lib.contains("/// Day 2: Dive!\npub struct Day02;"));
+        assert!(answers_exist);
+    }
+}
@@ -0,0 +1,340 @@
+//! Making sense of answer submissions, and keeping a log of them so that an answer already known to
+//! be wrong is never submitted again.
+
+use aoc_2021_common::Part;
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// What adventofcode.com made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint as to which way.
+    Incorrect,
+    /// The previous answer was too recent, so this one was not checked.
+    Wait { seconds: u64 },
+    /// The part has already been solved, so this answer was not checked.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Whether the answer is known to be wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::Wait { seconds } => write!(f, "answered too recently, wait {}s", seconds),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Reads the verdict out of the page returned for a submission.
+pub fn parse_verdict(page: &str) -> Result<Verdict, String> {
+    let verdict = if page.contains("That's the right answer") {
+        Verdict::Correct
+    } else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        }
+    } else if page.contains("You gave an answer too recently") {
+        Verdict::Wait { seconds: wait_seconds(page)? }
+    } else if page.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        return Err("the response did not say whether the answer was right".to_string());
+    };
+
+    Ok(verdict)
+}
+
+/// Parses e.g. "You have 1m 5s left to wait".
+fn wait_seconds(page: &str) -> Result<u64, String> {
+    let error = || "the response did not say how long to wait".to_string();
+
+    let start = page.find("You have ").ok_or_else(error)? + "You have ".len();
+    let end = start + page[start..].find(" left to wait").ok_or_else(error)?;
+
+    page[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let (number, scale) = if let Some(n) = amount.strip_suffix('s') {
+                (n, 1)
+            } else if let Some(n) = amount.strip_suffix('m') {
+                (n, 60)
+            } else if let Some(n) = amount.strip_suffix('h') {
+                (n, 3600)
+            } else {
+                return Err(error());
+            };
+            number.parse::<u64>().map(|n| n * scale).map_err(|_| error())
+        })
+        .sum()
+}
+
+/// Parses how long a wrong answer locks out the next one, e.g. "Please wait one minute before
+/// trying again", or 0 if the page does not say.
+pub fn lockout_seconds(page: &str) -> u64 {
+    let start = match page.find("lease wait ") {
+        Some(i) => i + "lease wait ".len(),
+        None => return 0,
+    };
+    let mut words = page[start..].split_whitespace();
+
+    let number = match words.next() {
+        Some("one") | Some("a") => Some(1),
+        Some(n) => n.parse::<u64>().ok(),
+        None => None,
+    };
+    let scale = match words.next() {
+        Some(unit) if unit.starts_with("second") => Some(1),
+        Some(unit) if unit.starts_with("minute") => Some(60),
+        Some(unit) if unit.starts_with("hour") => Some(3600),
+        _ => None,
+    };
+
+    number.zip(scale).map_or(0, |(n, scale)| n.saturating_mul(scale))
+}
+
+/// A submitted answer and its verdict.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// When the answer was submitted, in seconds since the Unix epoch.
+    pub time: u64,
+    /// How long a wrong answer locks out the next one, in seconds.
+    #[serde(default)]
+    pub lockout: u64,
+}
+
+impl Attempt {
+    /// When the next answer may be submitted, in seconds since the Unix epoch, if the server asked
+    /// for a wait.
+    fn wait_until(&self) -> Option<u64> {
+        match self.verdict {
+            Verdict::Wait { seconds } => Some(self.time.saturating_add(seconds)),
+            _ if self.lockout > 0 => Some(self.time.saturating_add(self.lockout)),
+            _ => None,
+        }
+    }
+}
+
+/// Every answer submitted so far, stored as one JSON object per line.
+#[derive(Debug)]
+pub struct AttemptLog {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl AttemptLog {
+    /// Reads the log at `path`, which is empty if the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let error = |e: &dyn fmt::Display| format!("{}: {}", path.display(), e);
+
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(error(&e)),
+        };
+
+        let attempts = text.lines()
+            .map(|line| serde_json::from_str(line).map_err(|e| error(&e)))
+            .collect::<Result<Vec<Attempt>, String>>()?;
+
+        Ok(Self { path: path.to_path_buf(), attempts })
+    }
+
+    /// Checks that `answer` is worth submitting at time `now`: the part is not solved yet, the
+    /// answer has not been rejected before nor lies beyond a rejected answer that was too high or
+    /// too low, and no wait is still in force.
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: u64) -> Result<(), String> {
+        let attempts = self.attempts.iter()
+            .filter(|a| a.day == day && a.part == part.number())
+            .collect::<Vec<&Attempt>>();
+
+        if let Some(correct) = attempts.iter().find(|a| a.verdict == Verdict::Correct) {
+            return Err(format!("already solved, with {}", correct.answer));
+        }
+
+        if let Some(wrong) = attempts.iter().find(|a| a.answer == answer && a.verdict.is_wrong()) {
+            return Err(format!("{} was already submitted, and was {}", answer, wrong.verdict));
+        }
+
+        if let Ok(value) = answer.parse::<i64>() {
+            for attempt in &attempts {
+                let rejected = match attempt.answer.parse::<i64>() {
+                    Ok(rejected) => rejected,
+                    Err(_) => continue,
+                };
+                let beyond = match attempt.verdict {
+                    Verdict::TooHigh => value >= rejected,
+                    Verdict::TooLow => value <= rejected,
+                    _ => false,
+                };
+                if beyond {
+                    let verdict = attempt.verdict;
+                    return Err(format!("{} must be {}, as {} was", answer, verdict, rejected));
+                }
+            }
+        }
+
+        // the wait applies to every part, not just the one submitted.
+        let wait_until = self.attempts.iter().filter_map(Attempt::wait_until).max();
+
+        match wait_until {
+            Some(until) if until > now => Err(format!("wait another {}s", until - now)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Adds an attempt to the log, saving it straight away.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), String> {
+        let save = || -> io::Result<()> {
+            if let Some(dir) = self.path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+            writeln!(file, "{}", serde_json::to_string(&attempt)?)
+        };
+        save().map_err(|e| format!("{}: {}", self.path.display(), e))?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env;
+    use std::process;
+
+    // typical pages returned for a submission, cut down to the message.
+    const CORRECT: &str = "<main><article><p>That's the right answer!  You are one gold star \
+        closer to finding the sleigh keys.</p></article></main>";
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too \
+        high.  If you're stuck, make sure you're using the full input data.  Please wait one \
+        minute before trying again.</p></article></main>";
+    const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too \
+        low.  Please wait one minute before trying again.</p></article></main>";
+    const WAIT: &str = "<main><article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again.  You have 1m 5s left to wait.</p>\
+        </article></main>";
+    const ALREADY_SOLVED: &str = "<main><article><p>You don't seem to be solving the right \
+        level.  Did you already complete it?</p></article></main>";
+
+    fn attempt(part: u8, answer: &str, verdict: Verdict, time: u64) -> Attempt {
+        Attempt { day: 1, part, answer: answer.to_string(), verdict, time, lockout: 0 }
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        assert_eq!(Ok(Verdict::Correct), parse_verdict(CORRECT));
+        assert_eq!(Ok(Verdict::TooHigh), parse_verdict(TOO_HIGH));
+        assert_eq!(Ok(Verdict::TooLow), parse_verdict(TOO_LOW));
+        assert_eq!(
+            Ok(Verdict::Incorrect),
+            parse_verdict("<p>That's not the right answer.  Please wait one minute.</p>")
+        );
+        assert_eq!(Ok(Verdict::Wait { seconds: 65 }), parse_verdict(WAIT));
+        assert_eq!(Ok(Verdict::AlreadySolved), parse_verdict(ALREADY_SOLVED));
+        assert!(parse_verdict("<html>Maintenance</html>").is_err());
+    }
+
+    #[test]
+    fn test_lockout_seconds() {
+        assert_eq!(60, lockout_seconds(TOO_HIGH));
+        assert_eq!(60, lockout_seconds(TOO_LOW));
+        assert_eq!(
+            300,
+            lockout_seconds("<p>That's not the right answer.  Because you have guessed \
+                incorrectly 4 times on this puzzle, please wait 5 minutes before trying again.</p>")
+        );
+        assert_eq!(0, lockout_seconds(CORRECT));
+        assert_eq!(0, lockout_seconds(WAIT));
+    }
+
+    #[test]
+    fn test_check() {
+        let log = AttemptLog {
+            path: PathBuf::new(),
+            attempts: vec![
+                attempt(1, "1700", Verdict::TooHigh, 100),
+                attempt(1, "1500", Verdict::TooLow, 200),
+                attempt(1, "1650", Verdict::Incorrect, 300),
+                attempt(2, "1653", Verdict::Wait { seconds: 60 }, 400),
+                attempt(2, "1653", Verdict::Correct, 500),
+            ],
+        };
+
+        assert_eq!(Ok(()), log.check(1, Part::One, "1624", 1000));
+        assert_eq!(
+            Err("1650 was already submitted, and was incorrect".to_string()),
+            log.check(1, Part::One, "1650", 1000)
+        );
+        assert_eq!(
+            Err("1800 must be too high, as 1700 was".to_string()),
+            log.check(1, Part::One, "1800", 1000)
+        );
+        assert_eq!(
+            Err("1499 must be too low, as 1500 was".to_string()),
+            log.check(1, Part::One, "1499", 1000)
+        );
+        assert_eq!(Err("wait another 10s".to_string()), log.check(1, Part::One, "1624", 450));
+        assert_eq!(
+            Err("already solved, with 1653".to_string()),
+            log.check(1, Part::Two, "1654", 1000)
+        );
+        assert_eq!(Ok(()), log.check(2, Part::One, "1700", 1000));
+
+        // a wrong answer holds off the next one, whichever part it is for.
+        let mut wrong = attempt(1, "1700", Verdict::TooHigh, 1000);
+        wrong.lockout = 300;
+        let log = AttemptLog { path: PathBuf::new(), attempts: vec![wrong] };
+        assert_eq!(Err("wait another 60s".to_string()), log.check(1, Part::Two, "42", 1240));
+        assert_eq!(Ok(()), log.check(1, Part::One, "1624", 1300));
+
+        // logs written before lockouts were recorded still load.
+        let old: Attempt = serde_json::from_str(
+            r#"{"day": 1, "part": 1, "answer": "1700", "verdict": "too_high", "time": 100}"#,
+        ).unwrap();
+        assert_eq!(attempt(1, "1700", Verdict::TooHigh, 100), old);
+    }
+
+    #[test]
+    fn test_record() {
+        let path = env::temp_dir().join(format!("aoc2021_attempts_{}.jsonl", process::id()));
+
+        let mut log = AttemptLog::load(&path).unwrap();
+        log.record(attempt(1, "1700", Verdict::TooHigh, 100)).unwrap();
+        log.record(attempt(1, "1624", Verdict::Correct, 200)).unwrap();
+
+        let reloaded = AttemptLog::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(log.attempts, reloaded.unwrap().attempts);
+    }
+}
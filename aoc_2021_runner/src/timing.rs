@@ -0,0 +1,104 @@
+use aoc_2021_common::{Answer, Part};
+
+use std::time::Duration;
+
+/// How long each stage of solving a day took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u8,
+    /// Time taken to read and parse the input.
+    pub parse: Duration,
+    /// Time taken by each part, if it was solved.
+    pub parts: [Option<Duration>; 2],
+}
+
+impl Timing {
+    pub fn new(day: u8, parse: Duration, answers: &[Answer]) -> Self {
+        let mut parts = [None; 2];
+        for answer in answers {
+            parts[answer.part.number() as usize - 1] = Some(answer.elapsed);
+        }
+
+        Self { day, parse, parts }
+    }
+
+    pub fn part(&self, part: Part) -> Option<Duration> {
+        self.parts[part.number() as usize - 1]
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse + self.parts.iter().flatten().sum::<Duration>()
+    }
+}
+
+/// A table of the stages of each day, most expensive day first, followed by their sums. Parts that
+/// were not solved are shown as "-".
+pub fn table(timings: &[Timing]) -> String {
+    let mut sorted = timings.iter().collect::<Vec<&Timing>>();
+    sorted.sort_by(|a, b| b.total().cmp(&a.total()).then(a.day.cmp(&b.day)));
+
+    let mut result = row(["Day", "Parse", "Part 1", "Part 2", "Total"].map(String::from));
+
+    for t in sorted {
+        result += &row([
+            format!("{:02}", t.day),
+            cell(Some(t.parse)),
+            cell(t.part(Part::One)),
+            cell(t.part(Part::Two)),
+            cell(Some(t.total())),
+        ]);
+    }
+
+    let sum = |f: &dyn Fn(&Timing) -> Option<Duration>| {
+        let durations = timings.iter().filter_map(f).collect::<Vec<Duration>>();
+        if durations.is_empty() { None } else { Some(durations.into_iter().sum()) }
+    };
+    result += &row([
+        "All".to_string(),
+        cell(sum(&|t| Some(t.parse))),
+        cell(sum(&|t| t.part(Part::One))),
+        cell(sum(&|t| t.part(Part::Two))),
+        cell(sum(&|t| Some(t.total()))),
+    ]);
+
+    result
+}
+
+fn cell(duration: Option<Duration>) -> String {
+    duration.map_or_else(|| "-".to_string(), |d| format!("{:.3?}", d))
+}
+
+fn row(cells: [String; 5]) -> String {
+    let [day, parse, part_1, part_2, total] = cells;
+    format!("{:>5} {:>12} {:>12} {:>12} {:>12}\n", day, parse, part_1, part_2, total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(day: u8, part: Part, millis: u64) -> Answer {
+        Answer { day, part, value: String::new(), elapsed: Duration::from_millis(millis) }
+    }
+
+    #[test]
+    fn test_table() {
+        let timings = [
+            Timing::new(1, Duration::from_millis(1), &[answer(1, Part::One, 2)]),
+            Timing::new(
+                2,
+                Duration::from_millis(3),
+                &[answer(2, Part::One, 1), answer(2, Part::Two, 4)],
+            ),
+        ];
+        assert_eq!(Duration::from_millis(8), timings[1].total());
+
+        let table = table(&timings);
+        let rows = table.lines().map(|l| l.split_whitespace().collect()).collect::<Vec<Vec<&str>>>();
+
+        assert_eq!(vec!["Day", "Parse", "Part", "1", "Part", "2", "Total"], rows[0]);
+        assert_eq!(vec!["02", "3.000ms", "1.000ms", "4.000ms", "8.000ms"], rows[1]);
+        assert_eq!(vec!["01", "1.000ms", "2.000ms", "-", "3.000ms"], rows[2]);
+        assert_eq!(vec!["All", "4.000ms", "3.000ms", "4.000ms", "11.000ms"], rows[3]);
+    }
+}
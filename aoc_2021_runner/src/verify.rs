@@ -0,0 +1,138 @@
+use aoc_2021_common::parse::{numbered, parse_lines};
+use aoc_2021_common::{Answer, ParseError, Part};
+
+use std::io::BufRead;
+
+/// The recorded answers to a day's parts, as read from its `answers.txt`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Expected {
+    answers: [Option<String>; 2],
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        self.answers[part.number() as usize - 1].as_deref()
+    }
+
+    fn slot(&mut self, part: Part) -> &mut Option<String> {
+        &mut self.answers[part.number() as usize - 1]
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String },
+    /// No answer has been recorded for the part.
+    Missing,
+}
+
+/// Parses an answers file with one "part: answer" entry per line, e.g. "1: 1624". An entry with
+/// nothing after the ':' takes the lines that follow it instead, for answers spanning several lines.
+pub fn parse_answers(input: impl BufRead) -> Result<Expected, ParseError> {
+    parse_lines(input, |lines| {
+        let mut expected = Expected::default();
+        let mut multi_line = None;
+
+        for line in numbered(lines) {
+            let entry = line.text
+                .split_once(':')
+                .and_then(|(part, value)| Some((part.parse::<Part>().ok()?, part, value)));
+
+            match (entry, multi_line) {
+                (Some((part, part_str, value)), _) => {
+                    if expected.get(part).is_some() {
+                        return Err(line.error(part_str, "a part without an earlier answer"));
+                    }
+
+                    let value = value.trim();
+                    *expected.slot(part) = Some(value.to_string());
+                    multi_line = if value.is_empty() { Some(part) } else { None };
+                },
+                (None, Some(part)) => {
+                    let value = expected.slot(part).as_mut().unwrap();
+                    if !value.is_empty() {
+                        value.push('\n');
+                    }
+                    value.push_str(line.text);
+                },
+                (None, None) => {
+                    if !line.text.trim().is_empty() {
+                        return Err(line.error(line.text, "\"1:\" or \"2:\" followed by an answer"));
+                    }
+                },
+            }
+        }
+
+        // trailing blank lines are not part of a multi-line answer.
+        for value in expected.answers.iter_mut().flatten() {
+            value.truncate(value.trim_end().len());
+        }
+
+        Ok(expected)
+    })
+}
+
+/// Counts of each outcome over a verification run.
+#[derive(Debug, Default)]
+pub struct Tally {
+    pub passed: usize,
+    pub failed: usize,
+    pub missing: usize,
+}
+
+impl Tally {
+    pub fn record(&mut self, outcome: &Outcome) {
+        match outcome {
+            Outcome::Pass => self.passed += 1,
+            Outcome::Fail { .. } => self.failed += 1,
+            Outcome::Missing => self.missing += 1,
+        }
+    }
+}
+
+pub fn check(expected: &Expected, answer: &Answer) -> Outcome {
+    match expected.get(answer.part) {
+        Some(value) if value == answer.value => Outcome::Pass,
+        Some(value) => Outcome::Fail { expected: value.to_string() },
+        None => Outcome::Missing,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    const ANSWERS: &str = "1: 671\n2:\n#..#\n####\n\n";
+
+    fn answer(part: Part, value: &str) -> Answer {
+        Answer { day: 13, part, value: value.to_string(), elapsed: Duration::ZERO }
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let expected = parse_answers(ANSWERS.as_bytes()).unwrap();
+        assert_eq!(Some("671"), expected.get(Part::One));
+        assert_eq!(Some("#..#\n####"), expected.get(Part::Two));
+
+        let expected = parse_answers("2: 14\n".as_bytes()).unwrap();
+        assert_eq!(None, expected.get(Part::One));
+
+        let err = parse_answers("1: 671\n1: 672\n".as_bytes()).unwrap_err();
+        assert_eq!("2:1: expected a part without an earlier answer, found \"1\"", err.to_string());
+    }
+
+    #[test]
+    fn test_check() {
+        let expected = parse_answers("1: 671\n".as_bytes()).unwrap();
+
+        assert_eq!(Outcome::Pass, check(&expected, &answer(Part::One, "671")));
+        assert_eq!(
+            Outcome::Fail { expected: "671".to_string() },
+            check(&expected, &answer(Part::One, "670"))
+        );
+        assert_eq!(Outcome::Missing, check(&expected, &answer(Part::Two, "14")));
+    }
+}
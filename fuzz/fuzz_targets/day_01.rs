@@ -0,0 +1,11 @@
+#![no_main]
+
+use aoc_2021_common::{solve_within, Solution, SOLVE_BUDGET};
+use aoc_2021_day_01::Day01;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = Day01::parse(data) {
+        solve_within::<Day01>(input, SOLVE_BUDGET).unwrap();
+    }
+});
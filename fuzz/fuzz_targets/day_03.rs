@@ -0,0 +1,11 @@
+#![no_main]
+
+use aoc_2021_common::{solve_within, Solution, SOLVE_BUDGET};
+use aoc_2021_day_03::Day03;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = Day03::parse(data) {
+        solve_within::<Day03>(input, SOLVE_BUDGET).unwrap();
+    }
+});
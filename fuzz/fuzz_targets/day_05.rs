@@ -0,0 +1,11 @@
+#![no_main]
+
+use aoc_2021_common::{solve_within, Solution, SOLVE_BUDGET};
+use aoc_2021_day_05::Day05;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = Day05::parse(data) {
+        solve_within::<Day05>(input, SOLVE_BUDGET).unwrap();
+    }
+});
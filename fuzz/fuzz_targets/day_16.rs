@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2021_common::{solve_within, Solution, SOLVE_BUDGET};
+use aoc_2021_day_16::Day16;
+use libfuzzer_sys::fuzz_target;
+
+// decoding the packets is part of parsing for this day.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = Day16::parse(data) {
+        solve_within::<Day16>(input, SOLVE_BUDGET).unwrap();
+    }
+});